@@ -0,0 +1,162 @@
+//! Archive formats shared by fixtures and assertions.
+
+use std::io;
+use std::path;
+
+use crate::walk;
+
+/// Archive format, chosen by file extension.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Format {
+    Tar,
+    TarGz,
+    TarZst,
+    Zip,
+}
+
+impl Format {
+    pub(crate) fn detect(archive: &path::Path) -> Option<Self> {
+        let name = archive.file_name()?.to_str()?.to_ascii_lowercase();
+        if name.ends_with(".tar") {
+            Some(Format::Tar)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(Format::TarGz)
+        } else if name.ends_with(".tar.zst") || name.ends_with(".tzst") {
+            Some(Format::TarZst)
+        } else if name.ends_with(".zip") {
+            Some(Format::Zip)
+        } else {
+            None
+        }
+    }
+}
+
+/// The type of an archive [`Member`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum MemberKind {
+    File(Vec<u8>),
+    Dir,
+    Symlink(path::PathBuf),
+    Other,
+}
+
+/// An entry within an archive, read into memory.
+#[derive(Clone, Debug)]
+pub(crate) struct Member {
+    pub(crate) path: path::PathBuf,
+    pub(crate) mode: Option<u32>,
+    pub(crate) kind: MemberKind,
+}
+
+/// Read every member of `archive`.
+pub(crate) fn read_members(archive: &path::Path) -> io::Result<Vec<Member>> {
+    let format = Format::detect(archive).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("unrecognized archive format: {}", archive.display()),
+        )
+    })?;
+    let file = io::BufReader::new(std::fs::File::open(archive)?);
+    match format {
+        Format::Tar | Format::TarGz | Format::TarZst => read_tar(format, file),
+        Format::Zip => read_zip(file),
+    }
+}
+
+#[cfg(feature = "tar")]
+fn read_tar<R>(format: Format, reader: R) -> io::Result<Vec<Member>>
+where
+    R: io::BufRead,
+{
+    match format {
+        Format::Tar => read_tar_entries(reader),
+        Format::TarGz => read_tar_entries(flate2::bufread::GzDecoder::new(reader)),
+        Format::TarZst => read_tar_entries(zstd::stream::read::Decoder::with_buffer(reader)?),
+        Format::Zip => unreachable!("zip is not a tar format"),
+    }
+}
+
+#[cfg(feature = "tar")]
+fn read_tar_entries<R>(reader: R) -> io::Result<Vec<Member>>
+where
+    R: io::Read,
+{
+    let mut archive = tar::Archive::new(reader);
+    let mut members = Vec::new();
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = walk::normalize(&entry.path()?);
+        let mode = entry.header().mode().ok().map(|m| m & 0o7777);
+        let entry_type = entry.header().entry_type();
+        let kind = if entry_type.is_file() {
+            let mut content = Vec::new();
+            io::Read::read_to_end(&mut entry, &mut content)?;
+            MemberKind::File(content)
+        } else if entry_type.is_dir() {
+            MemberKind::Dir
+        } else if entry_type.is_symlink() {
+            let target = entry.link_name()?.unwrap_or_default().into_owned();
+            MemberKind::Symlink(target)
+        } else {
+            MemberKind::Other
+        };
+        members.push(Member { path, mode, kind });
+    }
+    Ok(members)
+}
+
+#[cfg(not(feature = "tar"))]
+fn read_tar<R>(_format: Format, _reader: R) -> io::Result<Vec<Member>>
+where
+    R: io::BufRead,
+{
+    Err(unsupported("tar"))
+}
+
+#[cfg(feature = "zip")]
+fn read_zip<R>(reader: R) -> io::Result<Vec<Member>>
+where
+    R: io::Read + io::Seek,
+{
+    const S_IFMT: u32 = 0o170_000;
+    const S_IFLNK: u32 = 0o120_000;
+
+    let mut archive = zip::ZipArchive::new(reader)?;
+    let mut members = Vec::new();
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let path = walk::normalize(path::Path::new(entry.name()));
+        let unix_mode = entry.unix_mode();
+        let mode = unix_mode.map(|m| m & 0o7777);
+        let kind = if entry.is_dir() {
+            MemberKind::Dir
+        } else {
+            let mut content = Vec::new();
+            io::Read::read_to_end(&mut entry, &mut content)?;
+            if unix_mode.is_some_and(|m| m & S_IFMT == S_IFLNK) {
+                let target = String::from_utf8_lossy(&content).into_owned();
+                MemberKind::Symlink(target.into())
+            } else {
+                MemberKind::File(content)
+            }
+        };
+        members.push(Member { path, mode, kind });
+    }
+    Ok(members)
+}
+
+#[cfg(not(feature = "zip"))]
+fn read_zip<R>(_reader: R) -> io::Result<Vec<Member>>
+where
+    R: io::Read + io::Seek,
+{
+    Err(unsupported("zip"))
+}
+
+#[cfg(any(not(feature = "tar"), not(feature = "zip")))]
+pub(crate) fn unsupported(feature: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Other,
+        format!("the `{}` feature is required for this archive", feature),
+    )
+}
@@ -0,0 +1,341 @@
+//! Assertions on the content of archives.
+//!
+//! Requires the `tar` and/or `zip` features.  The format is chosen by the archive's extension, like
+//! [`PathExtract`].
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use assert_fs::assert::archive;
+//! use assert_fs::prelude::*;
+//!
+//! let temp = assert_fs::TempDir::new().unwrap();
+//! let package = temp.child("package.tar.gz");
+//!
+//! // ... produce package ...
+//!
+//! package.assert(archive::contains_entry("bin/tool").with_mode(0o755));
+//! package.assert(archive::entries_exactly(["bin/tool", "README.md"]));
+//!
+//! temp.close().unwrap();
+//! ```
+//!
+//! [`PathExtract`]: ../../fixture/trait.PathExtract.html
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::path;
+
+use predicates_core;
+
+use crate::archive::read_members;
+use crate::archive::Member;
+use crate::archive::MemberKind;
+use crate::walk;
+
+/// Predicate that an archive contains the entry at `path`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use assert_fs::assert::archive;
+/// use assert_fs::prelude::*;
+///
+/// let package = assert_fs::fixture::ChildPath::new("package.zip");
+/// package.assert(
+///     archive::contains_entry("bin/tool")
+///         .with_mode(0o755)
+///         .with_content("#!/bin/sh\n"),
+/// );
+/// ```
+pub fn contains_entry<P>(path: P) -> ArchiveEntryPredicate
+where
+    P: AsRef<path::Path>,
+{
+    ArchiveEntryPredicate {
+        path: walk::normalize(path.as_ref()),
+        mode: None,
+        content: None,
+    }
+}
+
+/// Predicate that an archive contains exactly the entries at `paths`, and nothing else.
+///
+/// Parent directories are implied, whether or not the archive records them, so `"bin/tool"` also
+/// allows `"bin"`.
+pub fn entries_exactly<I, P>(paths: I) -> ArchiveContentsPredicate
+where
+    I: IntoIterator<Item = P>,
+    P: AsRef<path::Path>,
+{
+    let mut expected = BTreeSet::new();
+    for path in paths {
+        insert_with_parents(&mut expected, &walk::normalize(path.as_ref()));
+    }
+    ArchiveContentsPredicate {
+        kind: ContentsKind::Exactly(expected),
+    }
+}
+
+/// Predicate that an archive has the same files, symlinks, and directories as `dir`.
+///
+/// File content and symlink targets must match.  Permissions and timestamps are not compared.
+pub fn matches_dir<P>(dir: P) -> ArchiveContentsPredicate
+where
+    P: AsRef<path::Path>,
+{
+    ArchiveContentsPredicate {
+        kind: ContentsKind::MatchesDir(dir.as_ref().to_owned()),
+    }
+}
+
+fn insert_with_parents(paths: &mut BTreeSet<path::PathBuf>, mut path: &path::Path) {
+    while path != path::Path::new("") {
+        paths.insert(path.to_owned());
+        path = path.parent().unwrap_or_else(|| path::Path::new(""));
+    }
+}
+
+fn list(members: &[Member]) -> String {
+    members
+        .iter()
+        .map(|m| m.path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn case<'a>(
+    pred: &'a dyn predicates_core::reflection::PredicateReflection,
+    expected: bool,
+    result: Result<Vec<Member>, String>,
+    violations: impl FnOnce(&[Member]) -> Vec<String>,
+) -> Option<predicates_core::reflection::Case<'a>> {
+    let (result, products) = match result {
+        Ok(members) => {
+            let violations = violations(&members);
+            let products = vec![
+                predicates_core::reflection::Product::new("actual", list(&members)),
+                predicates_core::reflection::Product::new("violations", violations.join(", ")),
+            ];
+            (violations.is_empty(), products)
+        }
+        Err(err) => (
+            false,
+            vec![predicates_core::reflection::Product::new("error", err)],
+        ),
+    };
+    if result != expected {
+        return None;
+    }
+    let case = products.into_iter().fold(
+        predicates_core::reflection::Case::new(Some(pred), result),
+        |case, product| case.add_product(product),
+    );
+    Some(case)
+}
+
+/// [Predicate] that checks a single archive entry.
+///
+/// This is created by [`contains_entry`].
+///
+/// [`contains_entry`]: fn.contains_entry.html
+/// [Predicate]: https://docs.rs/predicates-core/1.0.0/predicates_core/trait.Predicate.html
+#[derive(Debug, Clone)]
+pub struct ArchiveEntryPredicate {
+    path: path::PathBuf,
+    mode: Option<u32>,
+    content: Option<Vec<u8>>,
+}
+
+impl ArchiveEntryPredicate {
+    /// Require the entry's permission bits to be `mode`.
+    pub fn with_mode(mut self, mode: u32) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Require the entry to be a file containing `content`.
+    pub fn with_content<C>(mut self, content: C) -> Self
+    where
+        C: AsRef<[u8]>,
+    {
+        self.content = Some(content.as_ref().to_owned());
+        self
+    }
+
+    fn violations(&self, members: &[Member]) -> Vec<String> {
+        let member = match members.iter().rev().find(|m| m.path == self.path) {
+            Some(member) => member,
+            None => return vec![format!("missing {}", self.path.display())],
+        };
+        let mut violations = Vec::new();
+        if let Some(mode) = self.mode {
+            match member.mode {
+                Some(actual) if actual == mode => {}
+                Some(actual) => violations.push(format!("mode is {:o}", actual)),
+                None => violations.push("mode is not recorded".to_owned()),
+            }
+        }
+        if let Some(ref content) = self.content {
+            match member.kind {
+                MemberKind::File(ref actual) if actual == content => {}
+                MemberKind::File(ref actual) => {
+                    violations.push(format!("content is {:?}", String::from_utf8_lossy(actual)))
+                }
+                _ => violations.push("not a file".to_owned()),
+            }
+        }
+        violations
+    }
+}
+
+impl predicates_core::reflection::PredicateReflection for ArchiveEntryPredicate {}
+
+impl predicates_core::Predicate<path::Path> for ArchiveEntryPredicate {
+    fn eval(&self, item: &path::Path) -> bool {
+        read_members(item)
+            .map(|members| self.violations(&members).is_empty())
+            .unwrap_or(false)
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<predicates_core::reflection::Case<'a>> {
+        let result = read_members(variable).map_err(|e| e.to_string());
+        case(self, expected, result, |members| self.violations(members))
+    }
+}
+
+impl fmt::Display for ArchiveEntryPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "var contains {}", self.path.display())?;
+        if let Some(mode) = self.mode {
+            write!(f, " with mode {:o}", mode)?;
+        }
+        if let Some(ref content) = self.content {
+            write!(f, " with content {:?}", String::from_utf8_lossy(content))?;
+        }
+        Ok(())
+    }
+}
+
+/// [Predicate] that checks all of an archive's entries.
+///
+/// This is created by [`entries_exactly`] or [`matches_dir`].
+///
+/// [`entries_exactly`]: fn.entries_exactly.html
+/// [`matches_dir`]: fn.matches_dir.html
+/// [Predicate]: https://docs.rs/predicates-core/1.0.0/predicates_core/trait.Predicate.html
+#[derive(Debug, Clone)]
+pub struct ArchiveContentsPredicate {
+    kind: ContentsKind,
+}
+
+#[derive(Debug, Clone)]
+enum ContentsKind {
+    Exactly(BTreeSet<path::PathBuf>),
+    MatchesDir(path::PathBuf),
+}
+
+impl ArchiveContentsPredicate {
+    fn violations(&self, members: &[Member]) -> Vec<String> {
+        let mut actual = BTreeSet::new();
+        for member in members {
+            insert_with_parents(&mut actual, &member.path);
+        }
+        match self.kind {
+            ContentsKind::Exactly(ref expected) => diff_paths(expected, &actual),
+            ContentsKind::MatchesDir(ref dir) => {
+                let expected = match read_dir(dir) {
+                    Ok(expected) => expected,
+                    Err(err) => return vec![err],
+                };
+                let paths: BTreeSet<_> = expected.keys().cloned().collect();
+                let mut violations = diff_paths(&paths, &actual);
+                for member in members {
+                    let expected = match expected.get(&member.path) {
+                        Some(expected) => expected,
+                        None => continue,
+                    };
+                    let same = match (expected, &member.kind) {
+                        (MemberKind::Dir, MemberKind::Dir) => true,
+                        (MemberKind::File(e), MemberKind::File(a)) => e == a,
+                        (MemberKind::Symlink(e), MemberKind::Symlink(a)) => e == a,
+                        _ => false,
+                    };
+                    if !same {
+                        violations.push(format!("different {}", member.path.display()));
+                    }
+                }
+                violations
+            }
+        }
+    }
+}
+
+fn diff_paths(expected: &BTreeSet<path::PathBuf>, actual: &BTreeSet<path::PathBuf>) -> Vec<String> {
+    let missing = expected
+        .difference(actual)
+        .map(|p| format!("missing {}", p.display()));
+    let unexpected = actual
+        .difference(expected)
+        .map(|p| format!("unexpected {}", p.display()));
+    missing.chain(unexpected).collect()
+}
+
+fn read_dir(dir: &path::Path) -> Result<BTreeMap<path::PathBuf, MemberKind>, String> {
+    let mut entries = BTreeMap::new();
+    for entry in walk::entries(dir) {
+        let (rel, entry) = entry.map_err(|e| e.to_string())?;
+        let file_type = entry.file_type();
+        let kind = if file_type.is_dir() {
+            MemberKind::Dir
+        } else if file_type.is_symlink() {
+            MemberKind::Symlink(fs::read_link(entry.path()).map_err(|e| e.to_string())?)
+        } else if file_type.is_file() {
+            MemberKind::File(fs::read(entry.path()).map_err(|e| e.to_string())?)
+        } else {
+            MemberKind::Other
+        };
+        entries.insert(rel, kind);
+    }
+    Ok(entries)
+}
+
+impl predicates_core::reflection::PredicateReflection for ArchiveContentsPredicate {}
+
+impl predicates_core::Predicate<path::Path> for ArchiveContentsPredicate {
+    fn eval(&self, item: &path::Path) -> bool {
+        read_members(item)
+            .map(|members| self.violations(&members).is_empty())
+            .unwrap_or(false)
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<predicates_core::reflection::Case<'a>> {
+        let result = read_members(variable).map_err(|e| e.to_string());
+        case(self, expected, result, |members| self.violations(members))
+    }
+}
+
+impl fmt::Display for ArchiveContentsPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ContentsKind::Exactly(ref expected) => {
+                let expected = expected
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect::<Vec<_>>();
+                write!(f, "var contains exactly [{}]", expected.join(", "))
+            }
+            ContentsKind::MatchesDir(ref dir) => write!(f, "var matches {}", dir.display()),
+        }
+    }
+}
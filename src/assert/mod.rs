@@ -0,0 +1,1372 @@
+//! Filesystem assertions.
+//!
+//! See [`PathAssert`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use assert_fs::prelude::*;
+//! use predicates::prelude::*;
+//!
+//! let temp = assert_fs::TempDir::new().unwrap();
+//! let input_file = temp.child("foo.txt");
+//! input_file.touch().unwrap();
+//!
+//! // ... do something with input_file ...
+//!
+//! input_file.assert("");
+//! temp.child("bar.txt").assert(predicate::path::missing());
+//!
+//! temp.close().unwrap();
+//! ```
+//!
+//! [`PathAssert`]: trait.PathAssert.html
+
+use std::cmp;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::path;
+use std::thread;
+use std::time;
+
+use globset;
+use predicates;
+use predicates::path::PredicateFileContentExt;
+use predicates::str::PredicateStrExt;
+use predicates_core;
+use predicates_tree::CaseTreeExt;
+
+use crate::digest;
+use crate::fixture;
+use crate::paths;
+use crate::walk;
+
+#[cfg(any(feature = "tar", feature = "zip"))]
+pub mod archive;
+
+/// Assert the state of files within [`TempDir`].
+///
+/// This uses [`IntoPathPredicate`] to provide short-hands for common cases, accepting:
+/// - `Predicate<Path>` for validating a path.
+/// - `Predicate<str>` for validating the content of the file.
+/// - `&Path` which must have the same file content.
+/// - `&[u8]` or `&str` representing the content of the file.
+///
+/// See [`predicates`] for more predicates.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use predicates::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let input_file = temp.child("foo.txt");
+/// input_file.touch().unwrap();
+///
+/// // ... do something with input_file ...
+///
+/// input_file.assert("");
+/// temp.child("bar.txt").assert(predicate::path::missing());
+///
+/// temp.close().unwrap();
+/// ```
+///
+/// [`TempDir`]: ../struct.TempDir.html
+/// [`predicates`]: https://docs.rs/predicates
+/// [`IntoPathPredicate`]: trait.IntoPathPredicate.html
+pub trait PathAssert {
+    /// Assert the state of files within [`TempDir`].
+    ///
+    /// This uses [`IntoPathPredicate`] to provide short-hands for common cases, accepting:
+    /// - `Predicate<Path>` for validating a path.
+    /// - `Predicate<str>` for validating the content of the file.
+    /// - `&Path` which must have the same file content.
+    /// - `&[u8]` or `&str` representing the content of the file.
+    ///
+    /// See [`predicates`] for more predicates.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    /// use predicates::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let input_file = temp.child("foo.txt");
+    /// input_file.touch().unwrap();
+    ///
+    /// // ... do something with input_file ...
+    ///
+    /// input_file.assert("");
+    /// // or
+    /// input_file.assert(predicate::str::is_empty());
+    ///
+    /// temp.child("bar.txt").assert(predicate::path::missing());
+    ///
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    /// [`TempDir`]: ../struct.TempDir.html
+    /// [`predicates`]: https://docs.rs/predicates
+    /// [`IntoPathPredicate`]: trait.IntoPathPredicate.html
+    fn assert<I, P>(&self, pred: I) -> &Self
+    where
+        I: IntoPathPredicate<P>,
+        P: predicates_core::Predicate<path::Path>;
+
+    /// Assert the state of files within [`TempDir`], retrying until `timeout` expires.
+    ///
+    /// The predicate is re-evaluated until it passes.  If it is still failing once `timeout` has
+    /// elapsed, this panics with the last failing case.
+    ///
+    /// This accepts the same short-hands as [`assert`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use assert_fs::prelude::*;
+    /// use predicates::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let input_file = temp.child("foo.txt");
+    ///
+    /// let path = input_file.path().to_owned();
+    /// let writer = std::thread::spawn(move || std::fs::write(path, "done").unwrap());
+    ///
+    /// input_file.assert_eventually("done", Duration::from_secs(5));
+    ///
+    /// writer.join().unwrap();
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    /// [`TempDir`]: ../struct.TempDir.html
+    /// [`assert`]: #tymethod.assert
+    fn assert_eventually<I, P>(&self, pred: I, timeout: time::Duration) -> &Self
+    where
+        I: IntoPathPredicate<P>,
+        P: predicates_core::Predicate<path::Path>,
+        Self: AsRef<path::Path>,
+    {
+        assert_eventually(self.as_ref(), pred, timeout);
+        self
+    }
+}
+
+impl PathAssert for fixture::TempDir {
+    fn assert<I, P>(&self, pred: I) -> &Self
+    where
+        I: IntoPathPredicate<P>,
+        P: predicates_core::Predicate<path::Path>,
+    {
+        assert(self.path(), pred);
+        self
+    }
+}
+
+impl PathAssert for fixture::NamedTempFile {
+    fn assert<I, P>(&self, pred: I) -> &Self
+    where
+        I: IntoPathPredicate<P>,
+        P: predicates_core::Predicate<path::Path>,
+    {
+        assert(self.path(), pred);
+        self
+    }
+}
+
+impl PathAssert for fixture::ChildPath {
+    fn assert<I, P>(&self, pred: I) -> &Self
+    where
+        I: IntoPathPredicate<P>,
+        P: predicates_core::Predicate<path::Path>,
+    {
+        assert(self.path(), pred);
+        self
+    }
+}
+
+fn assert<I, P>(path: &path::Path, pred: I)
+where
+    I: IntoPathPredicate<P>,
+    P: predicates_core::Predicate<path::Path>,
+{
+    let pred = pred.into_path();
+    if let Some(case) = pred.find_case(false, path) {
+        panic!("Unexpected file, failed {}\npath={:?}", case.tree(), path);
+    }
+}
+
+fn assert_eventually<I, P>(path: &path::Path, pred: I, timeout: time::Duration)
+where
+    I: IntoPathPredicate<P>,
+    P: predicates_core::Predicate<path::Path>,
+{
+    const MAX_INTERVAL: time::Duration = time::Duration::from_millis(100);
+
+    let pred = pred.into_path();
+    // A timeout too large to represent waits forever.
+    let deadline = time::Instant::now().checked_add(timeout);
+    let mut interval = time::Duration::from_millis(1);
+    loop {
+        let case = match pred.find_case(false, path) {
+            Some(case) => case,
+            None => return,
+        };
+        let now = time::Instant::now();
+        let remaining = match deadline {
+            Some(deadline) => deadline.saturating_duration_since(now),
+            None => interval,
+        };
+        if remaining == time::Duration::from_secs(0) {
+            panic!(
+                "Unexpected file after {:?}, failed {}\npath={:?}",
+                timeout,
+                case.tree(),
+                path
+            );
+        }
+        thread::sleep(cmp::min(interval, remaining));
+        interval = cmp::min(interval * 2, MAX_INTERVAL);
+    }
+}
+
+/// Used by [`PathAssert`] to convert Self into the needed [`Predicate<Path>`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::path;
+///
+/// use assert_fs::prelude::*;
+/// use predicates::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+///
+/// // ... do something with input_file ...
+///
+/// temp.child("bar.txt").assert(predicate::path::missing()); // Uses IntoPathPredicate
+///
+/// temp.close().unwrap();
+/// ```
+///
+/// [`PathAssert`]: trait.PathAssert.html
+/// [`Predicate<Path>`]: https://docs.rs/predicates-core/0.9.0/predicates_core/trait.Predicate.html
+pub trait IntoPathPredicate<P>
+where
+    P: predicates_core::Predicate<path::Path>,
+{
+    /// The type of the predicate being returned.
+    type Predicate;
+
+    /// Convert to a predicate for testing a path.
+    fn into_path(self) -> P;
+}
+
+impl<P> IntoPathPredicate<P> for P
+where
+    P: predicates_core::Predicate<path::Path>,
+{
+    type Predicate = P;
+
+    fn into_path(self) -> Self::Predicate {
+        self
+    }
+}
+
+// Keep `predicates` concrete Predicates out of our public API.
+/// [Predicate] used by [`IntoPathPredicate`] for bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let input_file = temp.child("foo.txt");
+/// input_file.touch().unwrap();
+///
+/// // ... do something with input_file ...
+///
+/// input_file.assert(b"" as &[u8]); // uses BytesContentPathPredicate
+///
+/// temp.close().unwrap();
+/// ```
+///
+/// [`IntoPathPredicate`]: trait.IntoPathPredicate.html
+/// [Predicate]: https://docs.rs/predicates-core/1.0.0/predicates_core/trait.Predicate.html
+#[derive(Debug)]
+pub struct BytesContentPathPredicate(
+    predicates::path::FileContentPredicate<predicates::ord::EqPredicate<&'static [u8]>>,
+);
+
+impl BytesContentPathPredicate {
+    pub(crate) fn new(value: &'static [u8]) -> Self {
+        let pred = predicates::ord::eq(value).from_file_path();
+        BytesContentPathPredicate(pred)
+    }
+}
+
+impl predicates_core::reflection::PredicateReflection for BytesContentPathPredicate {
+    fn parameters<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = predicates_core::reflection::Parameter<'a>> + 'a> {
+        self.0.parameters()
+    }
+
+    /// Nested `Predicate`s of the current `Predicate`.
+    fn children<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = predicates_core::reflection::Child<'a>> + 'a> {
+        self.0.children()
+    }
+}
+
+impl predicates_core::Predicate<path::Path> for BytesContentPathPredicate {
+    fn eval(&self, item: &path::Path) -> bool {
+        self.0.eval(item)
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<predicates_core::reflection::Case<'a>> {
+        self.0.find_case(expected, variable)
+    }
+}
+
+impl fmt::Display for BytesContentPathPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl IntoPathPredicate<BytesContentPathPredicate> for &'static [u8] {
+    type Predicate = BytesContentPathPredicate;
+
+    fn into_path(self) -> Self::Predicate {
+        Self::Predicate::new(self)
+    }
+}
+
+// Keep `predicates` concrete Predicates out of our public API.
+/// [Predicate] used by `IntoPathPredicate` for `str`.
+///
+/// # Example
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let input_file = temp.child("foo.txt");
+/// input_file.touch().unwrap();
+///
+/// // ... do something with input_file ...
+///
+/// input_file.assert(""); // Uses StrContentPathPredicate
+///
+/// temp.close().unwrap();
+/// ```
+///
+/// [`IntoPathPredicate`]: trait.IntoPathPredicate.html
+/// [Predicate]: https://docs.rs/predicates-core/1.0.0/predicates_core/trait.Predicate.html
+#[derive(Debug, Clone)]
+pub struct StrContentPathPredicate {
+    value: String,
+    normalize: Normalize,
+    pred: predicates::path::FileContentPredicate<
+        predicates::str::Utf8Predicate<NormalizedStrPredicate>,
+    >,
+}
+
+impl StrContentPathPredicate {
+    pub(crate) fn new(value: String) -> Self {
+        Self::with_normalize(value, Normalize::default())
+    }
+
+    fn with_normalize(value: String, normalize: Normalize) -> Self {
+        let expected = normalize.apply(&value);
+        let pred = NormalizedStrPredicate {
+            normalize,
+            pred: predicates::str::similar(expected),
+        }
+        .from_utf8()
+        .from_file_path();
+        StrContentPathPredicate {
+            value,
+            normalize,
+            pred,
+        }
+    }
+
+    /// Treat `\r\n` and `\r` line endings as `\n`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let input_file = temp.child("foo.txt");
+    /// input_file.write_str("Hello\r\nWorld\r\n").unwrap();
+    ///
+    /// input_file.assert(assert_fs::assert::content("Hello\nWorld\n").normalize_newlines());
+    ///
+    /// temp.close().unwrap();
+    /// ```
+    pub fn normalize_newlines(mut self) -> Self {
+        self.normalize.newlines = true;
+        Self::with_normalize(self.value, self.normalize)
+    }
+
+    /// Ignore whitespace at the end of each line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let input_file = temp.child("foo.txt");
+    /// input_file.write_str("Hello  \nWorld\t\n").unwrap();
+    ///
+    /// input_file.assert(assert_fs::assert::content("Hello\nWorld\n").trim_trailing());
+    ///
+    /// temp.close().unwrap();
+    /// ```
+    pub fn trim_trailing(mut self) -> Self {
+        self.normalize.trailing_whitespace = true;
+        Self::with_normalize(self.value, self.normalize)
+    }
+
+    /// Ignore whether the content ends with a newline.
+    ///
+    /// Only one trailing `\n` or `\r\n` is ignored, so blank lines at the end still count.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let input_file = temp.child("foo.txt");
+    /// input_file.write_str("Hello World\n").unwrap();
+    ///
+    /// input_file.assert(assert_fs::assert::content("Hello World").ignore_trailing_newline());
+    ///
+    /// temp.close().unwrap();
+    /// ```
+    pub fn ignore_trailing_newline(mut self) -> Self {
+        self.normalize.trailing_newline = true;
+        Self::with_normalize(self.value, self.normalize)
+    }
+}
+
+impl predicates_core::reflection::PredicateReflection for StrContentPathPredicate {
+    fn parameters<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = predicates_core::reflection::Parameter<'a>> + 'a> {
+        self.pred.parameters()
+    }
+
+    /// Nested `Predicate`s of the current `Predicate`.
+    fn children<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = predicates_core::reflection::Child<'a>> + 'a> {
+        self.pred.children()
+    }
+}
+
+impl predicates_core::Predicate<path::Path> for StrContentPathPredicate {
+    fn eval(&self, item: &path::Path) -> bool {
+        self.pred.eval(item)
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<predicates_core::reflection::Case<'a>> {
+        self.pred.find_case(expected, variable)
+    }
+}
+
+impl fmt::Display for StrContentPathPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.pred.fmt(f)
+    }
+}
+
+impl IntoPathPredicate<StrContentPathPredicate> for String {
+    type Predicate = StrContentPathPredicate;
+
+    fn into_path(self) -> Self::Predicate {
+        Self::Predicate::new(self)
+    }
+}
+
+impl IntoPathPredicate<StrContentPathPredicate> for &str {
+    type Predicate = StrContentPathPredicate;
+
+    fn into_path(self) -> Self::Predicate {
+        Self::Predicate::new(self.to_owned())
+    }
+}
+
+impl IntoPathPredicate<StrContentPathPredicate> for &String {
+    type Predicate = StrContentPathPredicate;
+
+    fn into_path(self) -> Self::Predicate {
+        Self::Predicate::new(self.to_owned())
+    }
+}
+
+/// Create a [`StrContentPathPredicate`] for customizing how file content is compared.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let input_file = temp.child("foo.txt");
+/// input_file.write_str("Hello World \r\n").unwrap();
+///
+/// input_file.assert(
+///     assert_fs::assert::content("Hello World")
+///         .normalize_newlines()
+///         .trim_trailing()
+///         .ignore_trailing_newline(),
+/// );
+///
+/// temp.close().unwrap();
+/// ```
+///
+/// [`StrContentPathPredicate`]: struct.StrContentPathPredicate.html
+pub fn content<S>(value: S) -> StrContentPathPredicate
+where
+    S: Into<String>,
+{
+    StrContentPathPredicate::new(value.into())
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+struct Normalize {
+    newlines: bool,
+    trailing_whitespace: bool,
+    trailing_newline: bool,
+}
+
+impl Normalize {
+    fn apply(self, value: &str) -> String {
+        let mut value = if self.newlines {
+            value.replace("\r\n", "\n").replace('\r', "\n")
+        } else {
+            value.to_owned()
+        };
+        if self.trailing_whitespace {
+            value = value
+                .split('\n')
+                .map(|line| line.trim_end())
+                .collect::<Vec<_>>()
+                .join("\n");
+        }
+        if self.trailing_newline {
+            let trimmed = value
+                .strip_suffix("\r\n")
+                .or_else(|| value.strip_suffix('\n'))
+                .map(str::len);
+            if let Some(trimmed) = trimmed {
+                value.truncate(trimmed);
+            }
+        }
+        value
+    }
+}
+
+#[derive(Debug, Clone)]
+struct NormalizedStrPredicate {
+    normalize: Normalize,
+    pred: predicates::str::DifferencePredicate,
+}
+
+impl predicates_core::reflection::PredicateReflection for NormalizedStrPredicate {
+    fn parameters<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = predicates_core::reflection::Parameter<'a>> + 'a> {
+        self.pred.parameters()
+    }
+
+    fn children<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = predicates_core::reflection::Child<'a>> + 'a> {
+        self.pred.children()
+    }
+}
+
+impl predicates_core::Predicate<str> for NormalizedStrPredicate {
+    fn eval(&self, item: &str) -> bool {
+        self.pred.eval(&self.normalize.apply(item))
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &str,
+    ) -> Option<predicates_core::reflection::Case<'a>> {
+        self.pred
+            .find_case(expected, &self.normalize.apply(variable))
+    }
+}
+
+impl fmt::Display for NormalizedStrPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.pred.fmt(f)
+    }
+}
+
+// Keep `predicates` concrete Predicates out of our public API.
+/// [Predicate] used by `IntoPathPredicate` for `str` predicates.
+///
+/// # Example
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use predicates::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let input_file = temp.child("foo.txt");
+/// input_file.touch().unwrap();
+///
+/// // ... do something with input_file ...
+///
+/// input_file.assert(predicate::str::is_empty()); // Uses StrPathPredicate
+///
+/// temp.close().unwrap();
+/// ```
+///
+/// [`IntoPathPredicate`]: trait.IntoPathPredicate.html
+/// [Predicate]: https://docs.rs/predicates-core/1.0.0/predicates_core/trait.Predicate.html
+#[derive(Debug, Clone)]
+pub struct StrPathPredicate<P: predicates_core::Predicate<str>>(
+    predicates::path::FileContentPredicate<predicates::str::Utf8Predicate<P>>,
+);
+
+impl<P> StrPathPredicate<P>
+where
+    P: predicates_core::Predicate<str>,
+{
+    pub(crate) fn new(value: P) -> Self {
+        let pred = value.from_utf8().from_file_path();
+        StrPathPredicate(pred)
+    }
+}
+
+impl<P> predicates_core::reflection::PredicateReflection for StrPathPredicate<P>
+where
+    P: predicates_core::Predicate<str>,
+{
+    fn parameters<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = predicates_core::reflection::Parameter<'a>> + 'a> {
+        self.0.parameters()
+    }
+
+    /// Nested `Predicate`s of the current `Predicate`.
+    fn children<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = predicates_core::reflection::Child<'a>> + 'a> {
+        self.0.children()
+    }
+}
+
+impl<P> predicates_core::Predicate<path::Path> for StrPathPredicate<P>
+where
+    P: predicates_core::Predicate<str>,
+{
+    fn eval(&self, item: &path::Path) -> bool {
+        self.0.eval(item)
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<predicates_core::reflection::Case<'a>> {
+        self.0.find_case(expected, variable)
+    }
+}
+
+impl<P> fmt::Display for StrPathPredicate<P>
+where
+    P: predicates_core::Predicate<str>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<P> IntoPathPredicate<StrPathPredicate<P>> for P
+where
+    P: predicates_core::Predicate<str>,
+{
+    type Predicate = StrPathPredicate<P>;
+
+    fn into_path(self) -> Self::Predicate {
+        Self::Predicate::new(self)
+    }
+}
+
+/// Predicate that the file content has the given SHA-256 digest.
+///
+/// `expected` is the hex-encoded digest.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let input_file = temp.child("foo.txt");
+/// input_file.touch().unwrap();
+///
+/// input_file.assert(assert_fs::assert::sha256(
+///     "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+/// ));
+///
+/// temp.close().unwrap();
+/// ```
+pub fn sha256<S>(expected: S) -> DigestPathPredicate
+where
+    S: AsRef<str>,
+{
+    DigestPathPredicate::new(digest::Algorithm::Sha256, expected.as_ref())
+}
+
+/// Predicate that the file content has the given BLAKE3 digest.
+///
+/// `expected` is the hex-encoded digest.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let input_file = temp.child("foo.txt");
+/// input_file.touch().unwrap();
+///
+/// input_file.assert(assert_fs::assert::blake3(
+///     "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262",
+/// ));
+///
+/// temp.close().unwrap();
+/// ```
+pub fn blake3<S>(expected: S) -> DigestPathPredicate
+where
+    S: AsRef<str>,
+{
+    DigestPathPredicate::new(digest::Algorithm::Blake3, expected.as_ref())
+}
+
+/// Predicate that the file content has the given CRC32 checksum.
+///
+/// `expected` is the hex-encoded checksum.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let input_file = temp.child("foo.txt");
+/// input_file.write_str("hello\n").unwrap();
+///
+/// input_file.assert(assert_fs::assert::crc32("363a3020"));
+///
+/// temp.close().unwrap();
+/// ```
+pub fn crc32<S>(expected: S) -> DigestPathPredicate
+where
+    S: AsRef<str>,
+{
+    DigestPathPredicate::new(digest::Algorithm::Crc32, expected.as_ref())
+}
+
+/// [Predicate] that checks a file's content against a checksum.
+///
+/// This is created by [`sha256`], [`blake3`], or [`crc32`].
+///
+/// [`sha256`]: fn.sha256.html
+/// [`blake3`]: fn.blake3.html
+/// [`crc32`]: fn.crc32.html
+/// [Predicate]: https://docs.rs/predicates-core/1.0.0/predicates_core/trait.Predicate.html
+#[derive(Debug, Clone)]
+pub struct DigestPathPredicate {
+    algorithm: digest::Algorithm,
+    expected: String,
+}
+
+impl DigestPathPredicate {
+    fn new(algorithm: digest::Algorithm, expected: &str) -> Self {
+        let expected = expected.trim().to_ascii_lowercase();
+        Self {
+            algorithm,
+            expected,
+        }
+    }
+
+    fn actual(&self, path: &path::Path) -> Result<String, String> {
+        digest::file_digest(self.algorithm, path).map_err(|e| e.to_string())
+    }
+}
+
+impl predicates_core::reflection::PredicateReflection for DigestPathPredicate {
+    fn parameters<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = predicates_core::reflection::Parameter<'a>> + 'a> {
+        let params = vec![predicates_core::reflection::Parameter::new(
+            "expected",
+            &self.expected,
+        )];
+        Box::new(params.into_iter())
+    }
+}
+
+impl predicates_core::Predicate<path::Path> for DigestPathPredicate {
+    fn eval(&self, item: &path::Path) -> bool {
+        self.actual(item)
+            .map(|actual| actual == self.expected)
+            .unwrap_or(false)
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<predicates_core::reflection::Case<'a>> {
+        let actual = self.actual(variable);
+        let result = actual
+            .as_ref()
+            .map(|actual| *actual == self.expected)
+            .unwrap_or(false);
+        if result != expected {
+            return None;
+        }
+        let product = match actual {
+            Ok(actual) => predicates_core::reflection::Product::new("actual", actual),
+            Err(err) => predicates_core::reflection::Product::new("error", err),
+        };
+        Some(predicates_core::reflection::Case::new(Some(self), result).add_product(product))
+    }
+}
+
+impl fmt::Display for DigestPathPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(var) == {}", self.algorithm, self.expected)
+    }
+}
+
+/// Predicate that a directory contains exactly `paths`, and nothing else.
+///
+/// `paths` are relative to the directory.  Their parent directories are implied, so
+/// `"sub/b.txt"` also allows `"sub"` to exist.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// temp.child("a.txt").touch().unwrap();
+/// temp.child("sub/b.txt").touch().unwrap();
+///
+/// temp.assert(assert_fs::assert::dir_contains_exactly(["a.txt", "sub/b.txt"]));
+///
+/// temp.close().unwrap();
+/// ```
+pub fn dir_contains_exactly<I, P>(paths: I) -> DirContentsPredicate
+where
+    I: IntoIterator<Item = P>,
+    P: AsRef<path::Path>,
+{
+    let mut expected = BTreeSet::new();
+    for path in paths {
+        let path = walk::normalize(path.as_ref());
+        let mut path = path.as_path();
+        while path != path::Path::new("") {
+            expected.insert(path.to_owned());
+            path = path.parent().unwrap_or_else(|| path::Path::new(""));
+        }
+    }
+    DirContentsPredicate {
+        kind: DirContentsKind::Exactly(expected),
+        patterns: Vec::new(),
+    }
+}
+
+/// Predicate that each of the glob `patterns` matches at least one entry in a directory.
+///
+/// `patterns` are relative to the directory.
+///
+/// # Panics
+///
+/// If a pattern is not a valid glob.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// temp.child("sub/b.txt").touch().unwrap();
+///
+/// temp.assert(assert_fs::assert::dir_contains(["**/*.txt"]));
+///
+/// temp.close().unwrap();
+/// ```
+pub fn dir_contains<I, S>(patterns: I) -> DirContentsPredicate
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    DirContentsPredicate {
+        kind: DirContentsKind::Contains,
+        patterns: compile_globs(patterns),
+    }
+}
+
+/// Predicate that none of the glob `patterns` match an entry in a directory.
+///
+/// `patterns` are relative to the directory.
+///
+/// # Panics
+///
+/// If a pattern is not a valid glob.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// temp.child("sub/b.txt").touch().unwrap();
+///
+/// temp.assert(assert_fs::assert::dir_lacks(["**/*.tmp"]));
+///
+/// temp.close().unwrap();
+/// ```
+pub fn dir_lacks<I, S>(patterns: I) -> DirContentsPredicate
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    DirContentsPredicate {
+        kind: DirContentsKind::Lacks,
+        patterns: compile_globs(patterns),
+    }
+}
+
+fn compile_globs<I, S>(patterns: I) -> Vec<globset::GlobMatcher>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    patterns
+        .into_iter()
+        .map(|pattern| {
+            let pattern = pattern.as_ref();
+            globset::GlobBuilder::new(pattern)
+                .literal_separator(true)
+                .build()
+                .unwrap_or_else(|e| panic!("Invalid glob {:?}: {}", pattern, e))
+                .compile_matcher()
+        })
+        .collect()
+}
+
+/// [Predicate] that checks the entries within a directory.
+///
+/// This is created by [`dir_contains_exactly`], [`dir_contains`], or [`dir_lacks`].
+///
+/// [`dir_contains_exactly`]: fn.dir_contains_exactly.html
+/// [`dir_contains`]: fn.dir_contains.html
+/// [`dir_lacks`]: fn.dir_lacks.html
+/// [Predicate]: https://docs.rs/predicates-core/1.0.0/predicates_core/trait.Predicate.html
+#[derive(Debug, Clone)]
+pub struct DirContentsPredicate {
+    kind: DirContentsKind,
+    patterns: Vec<globset::GlobMatcher>,
+}
+
+#[derive(Debug, Clone)]
+enum DirContentsKind {
+    Exactly(BTreeSet<path::PathBuf>),
+    Contains,
+    Lacks,
+}
+
+impl DirContentsPredicate {
+    fn list(path: &path::Path) -> Result<BTreeSet<path::PathBuf>, String> {
+        walk::entries(path)
+            .map(|entry| entry.map(|(rel, _)| rel).map_err(|e| e.to_string()))
+            .collect()
+    }
+
+    /// Entries that violate the predicate, described for the user.
+    fn violations(&self, listing: &BTreeSet<path::PathBuf>) -> Vec<String> {
+        match self.kind {
+            DirContentsKind::Exactly(ref expected) => {
+                let missing = expected
+                    .difference(listing)
+                    .map(|p| format!("missing {}", p.display()));
+                let unexpected = listing
+                    .difference(expected)
+                    .map(|p| format!("unexpected {}", p.display()));
+                missing.chain(unexpected).collect()
+            }
+            DirContentsKind::Contains => self
+                .patterns
+                .iter()
+                .filter(|glob| !listing.iter().any(|p| glob.is_match(p)))
+                .map(|glob| format!("nothing matched {}", glob.glob()))
+                .collect(),
+            DirContentsKind::Lacks => listing
+                .iter()
+                .filter_map(|p| {
+                    self.patterns
+                        .iter()
+                        .find(|glob| glob.is_match(p))
+                        .map(|glob| format!("{} matched {}", p.display(), glob.glob()))
+                })
+                .collect(),
+        }
+    }
+}
+
+impl predicates_core::reflection::PredicateReflection for DirContentsPredicate {}
+
+impl predicates_core::Predicate<path::Path> for DirContentsPredicate {
+    fn eval(&self, item: &path::Path) -> bool {
+        Self::list(item)
+            .map(|listing| self.violations(&listing).is_empty())
+            .unwrap_or(false)
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<predicates_core::reflection::Case<'a>> {
+        let (result, products) = match Self::list(variable) {
+            Ok(listing) => {
+                let violations = self.violations(&listing);
+                let actual = listing
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let products = vec![
+                    predicates_core::reflection::Product::new("actual", actual),
+                    predicates_core::reflection::Product::new("violations", violations.join(", ")),
+                ];
+                (violations.is_empty(), products)
+            }
+            Err(err) => (
+                false,
+                vec![predicates_core::reflection::Product::new("error", err)],
+            ),
+        };
+        if result != expected {
+            return None;
+        }
+        let case = products.into_iter().fold(
+            predicates_core::reflection::Case::new(Some(self), result),
+            |case, product| case.add_product(product),
+        );
+        Some(case)
+    }
+}
+
+impl fmt::Display for DirContentsPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let patterns = self
+            .patterns
+            .iter()
+            .map(|glob| glob.glob().glob())
+            .collect::<Vec<_>>();
+        match self.kind {
+            DirContentsKind::Exactly(ref expected) => {
+                let expected = expected
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect::<Vec<_>>();
+                write!(f, "var contains exactly [{}]", expected.join(", "))
+            }
+            DirContentsKind::Contains => write!(f, "var contains [{}]", patterns.join(", ")),
+            DirContentsKind::Lacks => write!(f, "var lacks [{}]", patterns.join(", ")),
+        }
+    }
+}
+
+/// Predicate that a directory contains each file in a [`Txtar`] tree, with the same content.
+///
+/// Other entries in the directory are ignored unless [`TxtarPredicate::exact`] is used.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// temp.child("hello.txt").write_str("Hello World\n").unwrap();
+/// temp.child("sub/empty.txt").touch().unwrap();
+///
+/// temp.assert(assert_fs::assert::matches_txtar(
+///     "-- hello.txt --\nHello World\n-- sub/empty.txt --\n",
+/// ));
+///
+/// temp.close().unwrap();
+/// ```
+///
+/// [`Txtar`]: ../fixture/struct.Txtar.html
+/// [`TxtarPredicate::exact`]: struct.TxtarPredicate.html#method.exact
+pub fn matches_txtar<T>(tree: T) -> TxtarPredicate
+where
+    T: Into<fixture::Txtar>,
+{
+    TxtarPredicate {
+        tree: tree.into(),
+        exact: false,
+    }
+}
+
+/// [Predicate] that checks a directory against a [`Txtar`] tree.
+///
+/// This is created by [`matches_txtar`].
+///
+/// [`matches_txtar`]: fn.matches_txtar.html
+/// [`Txtar`]: ../fixture/struct.Txtar.html
+/// [Predicate]: https://docs.rs/predicates-core/1.0.0/predicates_core/trait.Predicate.html
+#[derive(Debug, Clone)]
+pub struct TxtarPredicate {
+    tree: fixture::Txtar,
+    exact: bool,
+}
+
+impl TxtarPredicate {
+    /// Also require that the directory contains no files besides those in the tree.
+    ///
+    /// Directories are not compared, so empty directories are allowed.
+    pub fn exact(mut self) -> Self {
+        self.exact = true;
+        self
+    }
+
+    fn violations(&self, root: &path::Path) -> Result<Vec<String>, String> {
+        let mut violations = Vec::new();
+        let mut expected = BTreeSet::new();
+        for (rel, data) in self.tree.files() {
+            if !paths::is_enclosed(rel) {
+                return Err(format!(
+                    "txtar file escapes the directory: {}",
+                    rel.display()
+                ));
+            }
+            expected.insert(walk::normalize(rel));
+            match std::fs::read(root.join(rel)) {
+                Ok(ref actual) if actual.as_slice() == data.as_bytes() => {}
+                Ok(_) => violations.push(format!("different {}", rel.display())),
+                Err(ref err) if err.kind() == std::io::ErrorKind::NotFound => {
+                    violations.push(format!("missing {}", rel.display()))
+                }
+                Err(err) => violations.push(format!("unreadable {}: {}", rel.display(), err)),
+            }
+        }
+        if self.exact {
+            for entry in walk::entries(root) {
+                let (rel, entry) = entry.map_err(|e| e.to_string())?;
+                if entry.file_type().is_dir() {
+                    continue;
+                }
+                if !expected.contains(&rel) {
+                    violations.push(format!("unexpected {}", rel.display()));
+                }
+            }
+        }
+        Ok(violations)
+    }
+}
+
+impl predicates_core::reflection::PredicateReflection for TxtarPredicate {}
+
+impl predicates_core::Predicate<path::Path> for TxtarPredicate {
+    fn eval(&self, item: &path::Path) -> bool {
+        self.violations(item)
+            .map(|violations| violations.is_empty())
+            .unwrap_or(false)
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<predicates_core::reflection::Case<'a>> {
+        let (result, product) = match self.violations(variable) {
+            Ok(violations) => (
+                violations.is_empty(),
+                predicates_core::reflection::Product::new("violations", violations.join(", ")),
+            ),
+            Err(err) => (
+                false,
+                predicates_core::reflection::Product::new("error", err),
+            ),
+        };
+        if result != expected {
+            return None;
+        }
+        Some(predicates_core::reflection::Case::new(Some(self), result).add_product(product))
+    }
+}
+
+impl fmt::Display for TxtarPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let files = self
+            .tree
+            .files()
+            .map(|(p, _)| p.display().to_string())
+            .collect::<Vec<_>>();
+        if self.exact {
+            write!(f, "var matches exactly [{}]", files.join(", "))
+        } else {
+            write!(f, "var matches [{}]", files.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use predicates::prelude::*;
+
+    // Since IntoPathPredicate exists solely for conversion, test it under that scenario to ensure
+    // it works as expected.
+    fn convert_path<I, P>(pred: I) -> P
+    where
+        I: IntoPathPredicate<P>,
+        P: predicates_core::Predicate<path::Path>,
+    {
+        pred.into_path()
+    }
+
+    #[test]
+    fn into_path_from_pred() {
+        let pred = convert_path(predicate::eq(path::Path::new("hello.md")));
+        let case = pred.find_case(false, path::Path::new("hello.md"));
+        println!("Failing case: {:?}", case);
+        assert!(case.is_none());
+    }
+
+    #[test]
+    fn into_path_from_bytes() {
+        let pred = convert_path(b"hello\n" as &[u8]);
+        let case = pred.find_case(false, path::Path::new("tests/fixture/hello.txt"));
+        println!("Failing case: {:?}", case);
+        assert!(case.is_none());
+    }
+
+    #[test]
+    fn content_normalized() {
+        let pred = content("hello\r\n  ")
+            .normalize_newlines()
+            .trim_trailing()
+            .ignore_trailing_newline();
+        let case = pred.find_case(false, path::Path::new("tests/fixture/hello.txt"));
+        println!("Failing case: {:?}", case);
+        assert!(case.is_none());
+    }
+
+    #[test]
+    fn content_ignore_trailing_newline() {
+        let hello = path::Path::new("tests/fixture/hello.txt");
+
+        let pred = content("hello").ignore_trailing_newline();
+        assert!(pred.find_case(false, hello).is_none());
+
+        let pred = content("hello\n").ignore_trailing_newline();
+        assert!(pred.find_case(false, hello).is_none());
+
+        let pred = content("hello\n\n").ignore_trailing_newline();
+        assert!(pred.find_case(true, hello).is_none());
+    }
+
+    #[test]
+    fn content_not_normalized() {
+        let pred = content("hello");
+        let case = pred.find_case(true, path::Path::new("tests/fixture/hello.txt"));
+        println!("Failing case: {:?}", case);
+        assert!(case.is_none());
+    }
+
+    #[test]
+    fn digest_matches() {
+        let hello = path::Path::new("tests/fixture/hello.txt");
+        let preds = vec![
+            sha256("5891B5B522D5DF086D0FF0B110FBD9D21BB4FC7163AF34D08286A2E846F6BE03"),
+            blake3("8e4c7c1b99dbfd50e7a95185fead5ee1448fa904a2fdd778eaf5f2dbfd629a99"),
+            crc32("363a3020"),
+        ];
+        for pred in preds {
+            let case = pred.find_case(false, hello);
+            println!("Failing case: {:?}", case);
+            assert!(case.is_none());
+        }
+    }
+
+    #[test]
+    fn digest_mismatch() {
+        let pred = crc32("00000000");
+        let case = pred.find_case(false, path::Path::new("tests/fixture/hello.txt"));
+        println!("Failing case: {:?}", case);
+        assert!(case.is_some());
+    }
+
+    #[test]
+    fn dir_contents() {
+        let fixture = path::Path::new("tests/fixture");
+        let passing = vec![
+            dir_contains_exactly([".gitattributes", "hello.txt"]),
+            dir_contains_exactly(["./.gitattributes", "./hello.txt"]),
+            dir_contains(["*.txt"]),
+            dir_lacks(["*.md"]),
+        ];
+        for pred in passing {
+            let case = pred.find_case(false, fixture);
+            println!("Failing case: {:?}", case);
+            assert!(case.is_none());
+        }
+
+        let failing = vec![
+            dir_contains_exactly(["hello.txt"]),
+            dir_contains(["*.md"]),
+            dir_lacks(["*.txt"]),
+        ];
+        for pred in failing {
+            let case = pred.find_case(true, fixture);
+            println!("Passing case: {:?}", case);
+            assert!(case.is_none());
+        }
+    }
+
+    #[test]
+    fn into_path_from_str() {
+        let pred = convert_path("hello\n");
+        let case = pred.find_case(false, path::Path::new("tests/fixture/hello.txt"));
+        println!("Failing case: {:?}", case);
+        assert!(case.is_none());
+    }
+}
@@ -0,0 +1,90 @@
+//! Content digests shared by fixtures and assertions.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::path;
+
+use sha2::Digest;
+
+/// Hash algorithm used for checksumming file content.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Algorithm {
+    Sha256,
+    Blake3,
+    Crc32,
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Algorithm::Sha256 => write!(f, "sha256"),
+            Algorithm::Blake3 => write!(f, "blake3"),
+            Algorithm::Crc32 => write!(f, "crc32"),
+        }
+    }
+}
+
+enum Hasher {
+    Sha256(sha2::Sha256),
+    Blake3(Box<blake3::Hasher>),
+    Crc32(crc32fast::Hasher),
+}
+
+impl Hasher {
+    fn new(algorithm: Algorithm) -> Self {
+        match algorithm {
+            Algorithm::Sha256 => Hasher::Sha256(sha2::Sha256::new()),
+            Algorithm::Blake3 => Hasher::Blake3(Box::new(blake3::Hasher::new())),
+            Algorithm::Crc32 => Hasher::Crc32(crc32fast::Hasher::new()),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match *self {
+            Hasher::Sha256(ref mut h) => h.update(data),
+            Hasher::Blake3(ref mut h) => {
+                h.update(data);
+            }
+            Hasher::Crc32(ref mut h) => h.update(data),
+        }
+    }
+
+    fn finish(self) -> String {
+        match self {
+            Hasher::Sha256(h) => to_hex(&h.finalize()),
+            Hasher::Blake3(h) => h.finalize().to_hex().to_string(),
+            Hasher::Crc32(h) => format!("{:08x}", h.finalize()),
+        }
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Hex-encoded digest of `reader`'s content.
+pub(crate) fn read_digest<R>(algorithm: Algorithm, mut reader: R) -> io::Result<String>
+where
+    R: Read,
+{
+    let mut hasher = Hasher::new(algorithm);
+    let mut buffer = [0; 8 * 1024];
+    loop {
+        let len = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(len) => len,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        hasher.update(&buffer[..len]);
+    }
+    Ok(hasher.finish())
+}
+
+/// Hex-encoded digest of the file at `path`.
+pub(crate) fn file_digest(algorithm: Algorithm, path: &path::Path) -> io::Result<String> {
+    let file = fs::File::open(path)?;
+    read_digest(algorithm, file)
+}
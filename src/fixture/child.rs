@@ -1,5 +1,12 @@
+use std::ffi;
+use std::fmt;
+use std::io;
+use std::ops;
 use std::path;
 
+use super::errors::*;
+use crate::paths::is_enclosed;
+
 /// Access paths within [`TempDir`] for testing.
 ///
 /// See [`ChildPath`] trait implementations.
@@ -18,6 +25,9 @@ use std::path;
 pub trait PathChild {
     /// Access a path within the temp directory.
     ///
+    /// `path` is joined as-is, so an absolute path or `..` can point outside of the directory.
+    /// Use [`try_child`] for paths that aren't trusted.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -28,9 +38,47 @@ pub trait PathChild {
     /// println!("{}", temp.child("foo/bar.txt").path().display());
     /// temp.close().unwrap();
     /// ```
+    ///
+    /// [`try_child`]: #method.try_child
     fn child<P>(&self, path: P) -> ChildPath
     where
         P: AsRef<path::Path>;
+
+    /// Access a path within the temp directory, rejecting paths that would be outside of it.
+    ///
+    /// Absolute paths and paths whose `..` components climb above the current path fail with
+    /// [`FixtureKind::InvalidPath`].  The check is lexical, so symlinks within the directory are
+    /// not resolved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// assert!(temp.try_child("foo/../bar.txt").is_ok());
+    /// assert!(temp.try_child("../bar.txt").is_err());
+    /// assert!(temp.try_child("/etc/passwd").is_err());
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    /// [`FixtureKind::InvalidPath`]: enum.FixtureKind.html#variant.InvalidPath
+    fn try_child<P>(&self, path: P) -> Result<ChildPath, FixtureError>
+    where
+        P: AsRef<path::Path>,
+    {
+        let path = path.as_ref();
+        let child = self.child(path);
+        if !is_enclosed(path) {
+            return Err(FixtureError::new(FixtureKind::InvalidPath)
+                .with_target_path(child.path())
+                .chain(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{} escapes the fixture", path.display()),
+                )));
+        }
+        Ok(child)
+    }
 }
 
 impl PathChild for super::TempDir {
@@ -38,7 +86,20 @@ impl PathChild for super::TempDir {
     where
         P: AsRef<path::Path>,
     {
-        ChildPath::new(self.path().join(path.as_ref()))
+        ChildPath::in_root(self.path(), path.as_ref())
+    }
+}
+
+impl PathChild for super::NamedTempFile {
+    /// Access a path in the same temporary directory as the file.
+    ///
+    /// The path is removed along with the file.
+    fn child<P>(&self, path: P) -> ChildPath
+    where
+        P: AsRef<path::Path>,
+    {
+        let dir = self.path().parent().expect("within a temporary directory");
+        ChildPath::in_root(dir, path.as_ref())
     }
 }
 
@@ -47,7 +108,7 @@ impl PathChild for ChildPath {
     where
         P: AsRef<path::Path>,
     {
-        ChildPath::new(self.path().join(path.as_ref()))
+        self.with_path(self.path().join(path.as_ref()))
     }
 }
 
@@ -71,8 +132,10 @@ impl PathChild for ChildPath {
 /// ```
 ///
 /// [`TempDir`]: struct.TempDir.html
+#[derive(Debug)]
 pub struct ChildPath {
     path: path::PathBuf,
+    root: Option<path::PathBuf>,
 }
 
 impl ChildPath {
@@ -85,11 +148,150 @@ impl ChildPath {
     where
         P: Into<path::PathBuf>,
     {
-        Self { path: path.into() }
+        Self {
+            path: path.into(),
+            root: None,
+        }
+    }
+
+    /// `rel` within the fixture at `root`.
+    pub(crate) fn in_root(root: &path::Path, rel: &path::Path) -> Self {
+        // Avoid `join` adding a trailing separator for the root itself.
+        let path = if rel.as_os_str().is_empty() {
+            root.to_owned()
+        } else {
+            root.join(rel)
+        };
+        Self {
+            path,
+            root: Some(root.to_owned()),
+        }
+    }
+
+    /// Another path within the same fixture.
+    pub(crate) fn with_path(&self, path: path::PathBuf) -> Self {
+        Self {
+            path,
+            root: self.root.clone(),
+        }
     }
 
     /// Access the path.
     pub fn path(&self) -> &path::Path {
         &self.path
     }
+
+    /// The fixture this path is within, if any.
+    pub(crate) fn root(&self) -> Option<&path::Path> {
+        self.root.as_deref()
+    }
+
+    /// The path relative to the fixture it came from, like a [`TempDir`].
+    ///
+    /// This is the full path when it isn't within a fixture, like with [`ChildPath::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let report = temp.child("out").child("report.txt");
+    /// assert_eq!(report.relative_path(), Path::new("out/report.txt"));
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    /// [`TempDir`]: struct.TempDir.html
+    /// [`ChildPath::new`]: #method.new
+    pub fn relative_path(&self) -> &path::Path {
+        self.root
+            .as_ref()
+            .and_then(|root| self.path.strip_prefix(root).ok())
+            .unwrap_or(&self.path)
+    }
+
+    /// The directory containing this path, if it is within the same fixture.
+    ///
+    /// This is `None` for the root of a fixture, so walking up never leaves a [`TempDir`].  For a
+    /// path from [`ChildPath::new`], it is the parent of the path.
+    ///
+    /// This shadows [`Path::parent`]; use [`path`] to get at the plain path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let input_file = temp.child("subdir/foo.txt");
+    /// assert_eq!(
+    ///     input_file.parent().unwrap().path(),
+    ///     temp.child("subdir").path()
+    /// );
+    /// assert!(temp.child("subdir").parent().unwrap().parent().is_none());
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    /// [`TempDir`]: struct.TempDir.html
+    /// [`ChildPath::new`]: #method.new
+    /// [`Path::parent`]: https://doc.rust-lang.org/std/path/struct.Path.html#method.parent
+    /// [`path`]: #method.path
+    pub fn parent(&self) -> Option<ChildPath> {
+        if self.root.as_deref() == Some(self.path.as_path()) {
+            return None;
+        }
+        self.path.parent().map(|p| self.with_path(p.to_owned()))
+    }
+
+    /// A path named `name` in the same directory as this path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let input_file = temp.child("subdir/foo.txt");
+    /// assert_eq!(
+    ///     input_file.sibling("bar.txt").path(),
+    ///     temp.child("subdir/bar.txt").path()
+    /// );
+    /// temp.close().unwrap();
+    /// ```
+    pub fn sibling<P>(&self, name: P) -> ChildPath
+    where
+        P: AsRef<path::Path>,
+    {
+        match self.path.parent() {
+            Some(parent) => self.with_path(parent.join(name)),
+            None => self.with_path(name.as_ref().to_owned()),
+        }
+    }
+}
+
+impl AsRef<path::Path> for ChildPath {
+    fn as_ref(&self) -> &path::Path {
+        self.path()
+    }
+}
+
+impl AsRef<ffi::OsStr> for ChildPath {
+    fn as_ref(&self) -> &ffi::OsStr {
+        self.path().as_os_str()
+    }
+}
+
+impl ops::Deref for ChildPath {
+    type Target = path::Path;
+
+    fn deref(&self) -> &path::Path {
+        self.path()
+    }
+}
+
+impl fmt::Display for ChildPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.path().display().fmt(f)
+    }
 }
@@ -0,0 +1,74 @@
+use std::env;
+use std::marker::PhantomData;
+use std::path;
+use std::sync;
+
+use super::errors::*;
+use super::TempDir;
+
+/// Serializes every [`CurrentDirGuard`] in the process.
+static CURRENT_DIR_LOCK: sync::Mutex<()> = sync::Mutex::new(());
+
+impl TempDir {
+    /// Change the process's current directory to the temporary directory until the returned
+    /// guard is dropped.
+    ///
+    /// Only one guard exists at a time, so another `chdir` waits for this one to be dropped, even
+    /// on the same thread.  Code that doesn't hold a guard still sees the change.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// {
+    ///     let _cwd = temp.chdir().unwrap();
+    ///     std::fs::write("foo.txt", "Hello").unwrap();
+    /// }
+    /// temp.child("foo.txt").assert("Hello");
+    /// temp.close().unwrap();
+    /// ```
+    pub fn chdir(&self) -> Result<CurrentDirGuard<'_>, FixtureError> {
+        let lock = CURRENT_DIR_LOCK
+            .lock()
+            .unwrap_or_else(sync::PoisonError::into_inner);
+        let previous =
+            env::current_dir().chain_with(|| FixtureError::new(FixtureKind::CurrentDir))?;
+        env::set_current_dir(self.path()).chain_with(|| {
+            FixtureError::new(FixtureKind::CurrentDir).with_target_path(self.path())
+        })?;
+        Ok(CurrentDirGuard {
+            previous,
+            _lock: lock,
+            _dir: PhantomData,
+        })
+    }
+}
+
+/// Restores the previous current directory when dropped.
+///
+/// This is created by [`TempDir::chdir`].
+///
+/// [`TempDir::chdir`]: struct.TempDir.html#method.chdir
+#[must_use = "the current directory is restored as soon as the guard is dropped"]
+#[derive(Debug)]
+pub struct CurrentDirGuard<'a> {
+    previous: path::PathBuf,
+    _lock: sync::MutexGuard<'static, ()>,
+    _dir: PhantomData<&'a TempDir>,
+}
+
+impl CurrentDirGuard<'_> {
+    /// The current directory from before the guard was created.
+    pub fn previous(&self) -> &path::Path {
+        &self.previous
+    }
+}
+
+impl Drop for CurrentDirGuard<'_> {
+    fn drop(&mut self) {
+        // Nothing to report to from a destructor; the lock is still released.
+        let _ = env::set_current_dir(&self.previous);
+    }
+}
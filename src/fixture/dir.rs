@@ -1,8 +1,12 @@
+use std::ffi;
+use std::fmt;
+use std::ops;
 use std::path;
 
 use tempfile;
 
 use super::errors::*;
+use super::ChildPath;
 
 /// A directory in the filesystem that is automatically deleted when
 /// it goes out of scope.
@@ -59,10 +63,12 @@ use super::errors::*;
 /// [`std::env::temp_dir()`]: https://doc.rust-lang.org/std/env/fn.temp_dir.html
 /// [`std::fs`]: http://doc.rust-lang.org/std/fs/index.html
 /// [`std::process::exit()`]: http://doc.rust-lang.org/std/process/fn.exit.html
+#[derive(Debug)]
 pub struct TempDir {
     temp: Inner,
 }
 
+#[derive(Debug)]
 enum Inner {
     Temp(tempfile::TempDir),
     Persisted(path::PathBuf),
@@ -136,7 +142,7 @@ impl TempDir {
     /// ```
     pub fn into_persistent(self) -> Self {
         let path = match self.temp {
-            Inner::Temp(temp) => temp.into_path(),
+            Inner::Temp(temp) => temp.keep(),
             Inner::Persisted(path) => path,
         };
         let temp = Inner::Persisted(path);
@@ -166,6 +172,31 @@ impl TempDir {
         }
     }
 
+    /// Always `None`: the temporary directory is the root of its fixture.
+    ///
+    /// This matches [`ChildPath::parent`], which stops at the fixture root, and shadows
+    /// [`Path::parent`] so `temp.parent()` can't point outside of the fixture by accident.  Use
+    /// `temp.path().parent()` for the directory it was created in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use assert_fs::fixture::TempDir;
+    ///
+    /// let tmp_dir = TempDir::new().unwrap();
+    ///
+    /// assert!(tmp_dir.parent().is_none());
+    ///
+    /// // Ensure deletion happens.
+    /// tmp_dir.close().unwrap();
+    /// ```
+    ///
+    /// [`ChildPath::parent`]: struct.ChildPath.html#method.parent
+    /// [`Path::parent`]: https://doc.rust-lang.org/std/path/struct.Path.html#method.parent
+    pub fn parent(&self) -> Option<ChildPath> {
+        None
+    }
+
     /// Closes and removes the temporary directory, returing a `Result`.
     ///
     /// Although `TempDir` removes the directory on drop, in the destructor
@@ -193,11 +224,39 @@ impl TempDir {
     /// ```
     pub fn close(self) -> Result<(), FixtureError> {
         match self.temp {
-            Inner::Temp(temp) => temp
-                .close()
-                .chain(FixtureError::new(FixtureKind::Cleanup))?,
+            Inner::Temp(temp) => {
+                let path = temp.path().to_owned();
+                temp.close()
+                    .chain_with(|| FixtureError::new(FixtureKind::Cleanup).with_target_path(path))?
+            }
             Inner::Persisted(_) => (),
         }
         Ok(())
     }
 }
+
+impl AsRef<path::Path> for TempDir {
+    fn as_ref(&self) -> &path::Path {
+        self.path()
+    }
+}
+
+impl AsRef<ffi::OsStr> for TempDir {
+    fn as_ref(&self) -> &ffi::OsStr {
+        self.path().as_os_str()
+    }
+}
+
+impl ops::Deref for TempDir {
+    type Target = path::Path;
+
+    fn deref(&self) -> &path::Path {
+        self.path()
+    }
+}
+
+impl fmt::Display for TempDir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.path().display().fmt(f)
+    }
+}
@@ -0,0 +1,171 @@
+use std::env;
+use std::ffi;
+use std::fs;
+use std::path;
+use std::sync;
+
+use super::errors::*;
+
+/// Serializes every [`EnvGuard`] in the process.
+static ENV_LOCK: sync::Mutex<()> = sync::Mutex::new(());
+
+/// Environment variables pointing the user's home and XDG directories into a fixture.
+///
+/// Pass [`vars`] to [`Command::envs`] so the program under test reads and writes its
+/// configuration, caches, and data inside the fixture instead of the real home directory.
+///
+/// | Variable          | Directory      |
+/// |-------------------|----------------|
+/// | `HOME`            | `root/home`    |
+/// | `XDG_CONFIG_HOME` | `root/config`  |
+/// | `XDG_CACHE_HOME`  | `root/cache`   |
+/// | `XDG_DATA_HOME`   | `root/data`    |
+/// | `TMPDIR`          | `root/tmp`     |
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::fixture::EnvSandbox;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let sandbox = EnvSandbox::new(&temp).unwrap();
+/// let mut cmd = std::process::Command::new("my-tool");
+/// cmd.envs(sandbox.vars());
+/// temp.close().unwrap();
+/// ```
+///
+/// [`vars`]: #method.vars
+/// [`Command::envs`]: https://doc.rust-lang.org/std/process/struct.Command.html#method.envs
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnvSandbox {
+    vars: Vec<(&'static str, path::PathBuf)>,
+}
+
+impl EnvSandbox {
+    /// Create the sandbox directories under `root`.
+    pub fn new<P>(root: P) -> Result<Self, FixtureError>
+    where
+        P: AsRef<path::Path>,
+    {
+        let root = root.as_ref();
+        let vars = [
+            ("HOME", "home"),
+            ("XDG_CONFIG_HOME", "config"),
+            ("XDG_CACHE_HOME", "cache"),
+            ("XDG_DATA_HOME", "data"),
+            ("TMPDIR", "tmp"),
+        ]
+        .iter()
+        .map(|&(var, dir)| {
+            let dir = root.join(dir);
+            fs::create_dir_all(&dir)
+                .chain_with(|| FixtureError::new(FixtureKind::CreateDir).with_target_path(&dir))?;
+            Ok((var, dir))
+        })
+        .collect::<Result<_, FixtureError>>()?;
+        Ok(Self { vars })
+    }
+
+    /// The variables and their values, for [`Command::envs`].
+    ///
+    /// [`Command::envs`]: https://doc.rust-lang.org/std/process/struct.Command.html#method.envs
+    pub fn vars(&self) -> impl Iterator<Item = (&'static str, &path::Path)> {
+        self.vars.iter().map(|(var, dir)| (*var, dir.as_path()))
+    }
+
+    /// The directory for `HOME`.
+    pub fn home(&self) -> &path::Path {
+        self.get("HOME")
+    }
+
+    /// The directory for `XDG_CONFIG_HOME`.
+    pub fn config(&self) -> &path::Path {
+        self.get("XDG_CONFIG_HOME")
+    }
+
+    /// The directory for `XDG_CACHE_HOME`.
+    pub fn cache(&self) -> &path::Path {
+        self.get("XDG_CACHE_HOME")
+    }
+
+    /// The directory for `XDG_DATA_HOME`.
+    pub fn data(&self) -> &path::Path {
+        self.get("XDG_DATA_HOME")
+    }
+
+    /// The directory for `TMPDIR`.
+    pub fn tmp(&self) -> &path::Path {
+        self.get("TMPDIR")
+    }
+
+    fn get(&self, var: &str) -> &path::Path {
+        self.vars
+            .iter()
+            .find(|(v, _)| *v == var)
+            .map(|(_, dir)| dir.as_path())
+            .expect("every variable is set by `new`")
+    }
+
+    /// Set the variables for the whole process until the returned guard is dropped.
+    ///
+    /// Prefer [`vars`] with a child process where possible.  Guards are exclusive in the same way
+    /// as [`TempDir::chdir`]'s.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::fixture::EnvSandbox;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let sandbox = EnvSandbox::new(&temp).unwrap();
+    /// {
+    ///     let _env = sandbox.apply();
+    ///     assert_eq!(std::env::var_os("HOME").unwrap(), sandbox.home());
+    /// }
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    /// [`vars`]: #method.vars
+    /// [`TempDir::chdir`]: struct.TempDir.html#method.chdir
+    pub fn apply(&self) -> EnvGuard {
+        let lock = ENV_LOCK
+            .lock()
+            .unwrap_or_else(sync::PoisonError::into_inner);
+        let previous = self
+            .vars
+            .iter()
+            .map(|(var, dir)| {
+                let previous = env::var_os(var);
+                env::set_var(var, dir);
+                (*var, previous)
+            })
+            .collect();
+        EnvGuard {
+            previous,
+            _lock: lock,
+        }
+    }
+}
+
+/// Restores the previous environment variables when dropped.
+///
+/// This is created by [`EnvSandbox::apply`].
+///
+/// [`EnvSandbox::apply`]: struct.EnvSandbox.html#method.apply
+#[must_use = "the environment is restored as soon as the guard is dropped"]
+#[derive(Debug)]
+pub struct EnvGuard {
+    previous: Vec<(&'static str, Option<ffi::OsString>)>,
+    _lock: sync::MutexGuard<'static, ()>,
+}
+
+impl Drop for EnvGuard {
+    fn drop(&mut self) {
+        for (var, previous) in self.previous.drain(..) {
+            match previous {
+                Some(value) => env::set_var(var, value),
+                None => env::remove_var(var),
+            }
+        }
+    }
+}
@@ -2,6 +2,10 @@
 
 use std::error::Error;
 use std::fmt;
+use std::io;
+use std::path;
+
+use ignore;
 
 pub(crate) trait ChainError {
     fn chain<F>(self, cause: F) -> Self
@@ -42,6 +46,7 @@ where
 
 /// Fixture initialization cause.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::manual_non_exhaustive)]
 pub enum FixtureKind {
     /// Failed when walking the source tree.
     Walk,
@@ -49,8 +54,26 @@ pub enum FixtureKind {
     CopyFile,
     /// Failed when writing to a file.
     WriteFile,
+    /// Failed when reading a file.
+    ReadFile,
     /// Failed when creating a directory.
     CreateDir,
+    /// Failed when extracting an archive.
+    Extract,
+    /// Failed when reading metadata.
+    Metadata,
+    /// Failed when setting permissions.
+    Permissions,
+    /// Failed when creating a symlink.
+    Symlink,
+    /// Failed when removing a file or directory.
+    Remove,
+    /// Failed when renaming a file or directory.
+    Rename,
+    /// A path was outside of the fixture.
+    InvalidPath,
+    /// Failed when changing the current directory.
+    CurrentDir,
     /// Failed to cleanup fixture.
     Cleanup,
     #[doc(hidden)]
@@ -63,7 +86,16 @@ impl fmt::Display for FixtureKind {
             FixtureKind::Walk => write!(f, "Failed when walking the source tree,"),
             FixtureKind::CopyFile => write!(f, "Failed when copying a file."),
             FixtureKind::WriteFile => write!(f, "Failed when writing to a file."),
+            FixtureKind::ReadFile => write!(f, "Failed when reading a file."),
             FixtureKind::CreateDir => write!(f, "Failed when creating a directory."),
+            FixtureKind::Extract => write!(f, "Failed when extracting an archive."),
+            FixtureKind::Metadata => write!(f, "Failed when reading metadata."),
+            FixtureKind::Permissions => write!(f, "Failed when setting permissions."),
+            FixtureKind::Symlink => write!(f, "Failed when creating a symlink."),
+            FixtureKind::Remove => write!(f, "Failed when removing a file or directory."),
+            FixtureKind::Rename => write!(f, "Failed when renaming a file or directory."),
+            FixtureKind::InvalidPath => write!(f, "The path is outside of the fixture."),
+            FixtureKind::CurrentDir => write!(f, "Failed when changing the current directory."),
             FixtureKind::Cleanup => write!(f, "Failed to cleanup fixture."),
             FixtureKind::NonExhaustive => unreachable!("Don't touch these."),
         }
@@ -74,29 +106,100 @@ impl fmt::Display for FixtureKind {
 #[derive(Debug)]
 pub struct FixtureError {
     kind: FixtureKind,
+    source_path: Option<path::PathBuf>,
+    target_path: Option<path::PathBuf>,
     cause: Option<Box<dyn Error + Send + Sync + 'static>>,
 }
 
 impl FixtureError {
     /// Create a `FixtureError`.
     pub fn new(kind: FixtureKind) -> Self {
-        Self { kind, cause: None }
+        Self {
+            kind,
+            source_path: None,
+            target_path: None,
+            cause: None,
+        }
+    }
+
+    /// Record the path being read from, like the file being copied.
+    pub fn with_source_path<P>(mut self, path: P) -> Self
+    where
+        P: Into<path::PathBuf>,
+    {
+        self.source_path = Some(path.into());
+        self
+    }
+
+    /// Record the path being written to, like the file being created.
+    pub fn with_target_path<P>(mut self, path: P) -> Self
+    where
+        P: Into<path::PathBuf>,
+    {
+        self.target_path = Some(path.into());
+        self
     }
 
     /// Fixture initialization cause.
     pub fn kind(&self) -> FixtureKind {
         self.kind
     }
-}
 
-impl Error for FixtureError {
-    fn description(&self) -> &str {
-        "Failed to initialize fixture"
+    /// The path being read from, if any.
+    pub fn source_path(&self) -> Option<&path::Path> {
+        self.source_path.as_deref()
     }
 
-    fn cause(&self) -> Option<&dyn Error> {
+    /// The path being written to, if any.
+    pub fn target_path(&self) -> Option<&path::Path> {
+        self.target_path.as_deref()
+    }
+
+    /// Whether the failure was caused by a missing file or directory.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let err = temp.child("missing.txt").read_to_string().unwrap_err();
+    /// assert!(err.is_not_found());
+    /// temp.close().unwrap();
+    /// ```
+    pub fn is_not_found(&self) -> bool {
+        self.io_error_kind() == Some(io::ErrorKind::NotFound)
+    }
+
+    /// Whether the failure was caused by insufficient permissions.
+    pub fn is_permission_denied(&self) -> bool {
+        self.io_error_kind() == Some(io::ErrorKind::PermissionDenied)
+    }
+
+    /// The kind of the first I/O error among the causes.
+    fn io_error_kind(&self) -> Option<io::ErrorKind> {
+        let mut next = Error::source(self);
+        while let Some(err) = next {
+            if let Some(err) = err.downcast_ref::<io::Error>() {
+                return Some(err.kind());
+            }
+            // `ignore::Error` does not report its I/O error as a source.
+            if let Some(err) = err
+                .downcast_ref::<ignore::Error>()
+                .and_then(|e| e.io_error())
+            {
+                return Some(err.kind());
+            }
+            next = err.source();
+        }
+        None
+    }
+}
+
+impl Error for FixtureError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         self.cause.as_ref().map(|c| {
-            let c: &dyn Error = c.as_ref();
+            let c: &(dyn Error + 'static) = c.as_ref();
             c
         })
     }
@@ -104,14 +207,17 @@ impl Error for FixtureError {
 
 impl fmt::Display for FixtureError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self.cause {
-            Some(ref cause) => write!(
-                f,
-                "Failed to initialize fixture: {}\nCause: {}",
-                self.kind, cause
-            ),
-            None => write!(f, "Failed to initialize fixture: {}", self.kind),
+        write!(f, "Failed to initialize fixture: {}", self.kind)?;
+        if let Some(ref path) = self.source_path {
+            write!(f, "\nSource: {}", path.display())?;
+        }
+        if let Some(ref path) = self.target_path {
+            write!(f, "\nTarget: {}", path.display())?;
+        }
+        if let Some(ref cause) = self.cause {
+            write!(f, "\nCause: {}", cause)?;
         }
+        Ok(())
     }
 }
 
@@ -0,0 +1,245 @@
+use std::fs;
+use std::io;
+use std::path;
+
+use super::errors::*;
+#[cfg(feature = "zip")]
+use super::tools;
+use super::ChildPath;
+use super::TempDir;
+#[cfg(any(not(feature = "tar"), not(feature = "zip")))]
+use crate::archive::unsupported;
+use crate::archive::Format;
+use crate::paths::is_enclosed;
+
+/// Extract archives into [`TempDir`].
+///
+/// Supported formats, chosen by the archive's extension:
+/// - `.tar`, `.tar.gz` / `.tgz`, and `.tar.zst` / `.tzst` with the `tar` feature.
+/// - `.zip` with the `zip` feature.
+///
+/// Entries that would be written outside of the current path, like absolute paths or paths
+/// containing `..`, fail with [`FixtureKind::Extract`].
+///
+/// [`TempDir`]: struct.TempDir.html
+/// [`FixtureKind::Extract`]: enum.FixtureKind.html#variant.Extract
+pub trait PathExtract {
+    /// Extract the `archive` into the current path.
+    ///
+    /// Permissions, modification times, and symlinks are preserved where the format records them.
+    /// Zip only records file modification times, without a timezone, so they are read as UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// temp.extract_from("tests/fixtures/project.tar.gz").unwrap();
+    /// temp.close().unwrap();
+    /// ```
+    fn extract_from<P>(&self, archive: P) -> Result<(), FixtureError>
+    where
+        P: AsRef<path::Path>;
+}
+
+impl PathExtract for TempDir {
+    fn extract_from<P>(&self, archive: P) -> Result<(), FixtureError>
+    where
+        P: AsRef<path::Path>,
+    {
+        extract(self.path(), archive.as_ref())
+    }
+}
+
+impl PathExtract for ChildPath {
+    fn extract_from<P>(&self, archive: P) -> Result<(), FixtureError>
+    where
+        P: AsRef<path::Path>,
+    {
+        extract(self.path(), archive.as_ref())
+    }
+}
+
+fn extract(target: &path::Path, archive: &path::Path) -> Result<(), FixtureError> {
+    let format = Format::detect(archive).ok_or_else(|| {
+        FixtureError::new(FixtureKind::Extract)
+            .with_source_path(archive)
+            .chain(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unrecognized archive format: {}", archive.display()),
+            ))
+    })?;
+    let extract_error = || {
+        FixtureError::new(FixtureKind::Extract)
+            .with_source_path(archive)
+            .with_target_path(target)
+    };
+    fs::create_dir_all(target)
+        .chain_with(|| FixtureError::new(FixtureKind::CreateDir).with_target_path(target))?;
+    let file = fs::File::open(archive).chain_with(extract_error)?;
+    let file = io::BufReader::new(file);
+    match format {
+        Format::Tar | Format::TarGz | Format::TarZst => extract_tar(target, format, file),
+        Format::Zip => extract_zip(target, file),
+    }
+    .chain_with(extract_error)
+}
+
+#[cfg(feature = "tar")]
+fn extract_tar<R>(target: &path::Path, format: Format, reader: R) -> io::Result<()>
+where
+    R: io::BufRead,
+{
+    match format {
+        Format::Tar => unpack_tar(target, reader),
+        Format::TarGz => unpack_tar(target, flate2::bufread::GzDecoder::new(reader)),
+        Format::TarZst => unpack_tar(target, zstd::stream::read::Decoder::with_buffer(reader)?),
+        Format::Zip => unreachable!("zip is not a tar format"),
+    }
+}
+
+#[cfg(feature = "tar")]
+fn unpack_tar<R>(target: &path::Path, reader: R) -> io::Result<()>
+where
+    R: io::Read,
+{
+    let mut archive = tar::Archive::new(reader);
+    archive.set_preserve_permissions(true);
+    archive.set_preserve_mtime(true);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.into_owned();
+        check_enclosed(&path)?;
+        // `unpack_in` also refuses to write through symlinks that point outside of `target`.
+        if !entry.unpack_in(target)? {
+            return Err(escaping_entry(&path));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "tar"))]
+fn extract_tar<R>(_target: &path::Path, _format: Format, _reader: R) -> io::Result<()>
+where
+    R: io::BufRead,
+{
+    Err(unsupported("tar"))
+}
+
+#[cfg(feature = "zip")]
+fn extract_zip<R>(target: &path::Path, reader: R) -> io::Result<()>
+where
+    R: io::Read + io::Seek,
+{
+    const S_IFMT: u32 = 0o170_000;
+    const S_IFLNK: u32 = 0o120_000;
+
+    let mut archive = zip::ZipArchive::new(reader)?;
+    let root = target.canonicalize()?;
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let rel = match entry.enclosed_name() {
+            Some(rel) => rel.to_owned(),
+            None => return Err(escaping_entry(path::Path::new(entry.name()))),
+        };
+        check_enclosed(&rel)?;
+        let dest = target.join(&rel);
+        check_inside(&root, &dest, &rel)?;
+        if entry.is_dir() {
+            fs::create_dir_all(&dest)?;
+            continue;
+        }
+        fs::create_dir_all(dest.parent().expect("at least `target` exists"))?;
+        if fs::symlink_metadata(&dest).is_ok() {
+            fs::remove_file(&dest)?;
+        }
+        let mode = entry.unix_mode();
+        if mode.is_some_and(|m| m & S_IFMT == S_IFLNK) {
+            let mut link = String::new();
+            io::Read::read_to_string(&mut entry, &mut link)?;
+            tools::symlink(path::Path::new(&link), &dest, false)?;
+            continue;
+        }
+        let mut file = fs::File::create(&dest)?;
+        io::copy(&mut entry, &mut file)?;
+        drop(file);
+        filetime::set_file_mtime(&dest, zip_mtime(entry.last_modified()))?;
+        set_mode(&dest, mode)?;
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "zip"))]
+fn extract_zip<R>(_target: &path::Path, _reader: R) -> io::Result<()>
+where
+    R: io::Read + io::Seek,
+{
+    Err(unsupported("zip"))
+}
+
+/// Reject entries that are absolute or climb out of the target.
+fn check_enclosed(rel: &path::Path) -> io::Result<()> {
+    if is_enclosed(rel) {
+        Ok(())
+    } else {
+        Err(escaping_entry(rel))
+    }
+}
+
+/// Reject writing through symlinks that point outside of `root`.
+///
+/// Missing directories will be created as real directories, so only the deepest existing
+/// ancestor of `dest` needs to resolve inside of `root`.
+#[cfg(feature = "zip")]
+fn check_inside(root: &path::Path, dest: &path::Path, rel: &path::Path) -> io::Result<()> {
+    let mut existing = dest.parent().expect("at least `target` exists");
+    while fs::symlink_metadata(existing).is_err() {
+        existing = existing.parent().expect("at least `target` exists");
+    }
+    if existing.canonicalize()?.starts_with(root) {
+        Ok(())
+    } else {
+        Err(escaping_entry(rel))
+    }
+}
+
+fn escaping_entry(rel: &path::Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("archive entry escapes the target: {}", rel.display()),
+    )
+}
+
+/// Convert a zip timestamp, taken to be UTC, to a file time.
+#[cfg(feature = "zip")]
+fn zip_mtime(time: zip::DateTime) -> filetime::FileTime {
+    // Days since the Unix epoch for a proleptic Gregorian date, from Howard Hinnant's
+    // `days_from_civil`.
+    let (month, day) = (i64::from(time.month()), i64::from(time.day()));
+    let year = i64::from(time.year()) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+    let seconds = days * 86_400
+        + i64::from(time.hour()) * 3_600
+        + i64::from(time.minute()) * 60
+        + i64::from(time.second());
+    filetime::FileTime::from_unix_time(seconds, 0)
+}
+
+#[cfg(all(feature = "zip", unix))]
+fn set_mode(path: &path::Path, mode: Option<u32>) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    match mode {
+        Some(mode) => fs::set_permissions(path, fs::Permissions::from_mode(mode & 0o7777)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(all(feature = "zip", not(unix)))]
+fn set_mode(_path: &path::Path, _mode: Option<u32>) -> io::Result<()> {
+    Ok(())
+}
@@ -1,9 +1,12 @@
 use std::ffi;
+use std::fmt;
+use std::ops;
 use std::path;
 
 use tempfile;
 
 use super::errors::*;
+use super::ChildPath;
 
 /// A potential file in the filesystem that is automatically deleted when
 /// it goes out of scope.
@@ -59,11 +62,13 @@ use super::errors::*;
 /// [`std::env::temp_dir()`]: https://doc.rust-lang.org/std/env/fn.temp_dir.html
 /// [`std::fs`]: http://doc.rust-lang.org/std/fs/index.html
 /// [`std::process::exit()`]: http://doc.rust-lang.org/std/process/fn.exit.html
+#[derive(Debug)]
 pub struct NamedTempFile {
     temp: Inner,
     path: path::PathBuf,
 }
 
+#[derive(Debug)]
 enum Inner {
     Temp(tempfile::TempDir),
     Persisted,
@@ -143,7 +148,7 @@ impl NamedTempFile {
         let mut temp = Inner::Persisted;
         ::std::mem::swap(&mut self.temp, &mut temp);
         if let Inner::Temp(temp) = temp {
-            temp.into_path();
+            let _ = temp.keep();
         }
 
         self
@@ -169,6 +174,55 @@ impl NamedTempFile {
         &self.path
     }
 
+    /// The temporary directory containing the file.
+    ///
+    /// This is always `Some`; the signature matches [`ChildPath::parent`].  It shadows
+    /// [`Path::parent`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use assert_fs::fixture::NamedTempFile;
+    ///
+    /// let tmp_file = NamedTempFile::new("foo.rs").unwrap();
+    ///
+    /// assert!(tmp_file.parent().unwrap().path().is_dir());
+    ///
+    /// // Ensure deletion happens.
+    /// tmp_file.close().unwrap();
+    /// ```
+    ///
+    /// [`ChildPath::parent`]: struct.ChildPath.html#method.parent
+    /// [`Path::parent`]: https://doc.rust-lang.org/std/path/struct.Path.html#method.parent
+    pub fn parent(&self) -> Option<ChildPath> {
+        let dir = self.path.parent().expect("within a temporary directory");
+        Some(ChildPath::in_root(dir, path::Path::new("")))
+    }
+
+    /// A path named `name` in the same temporary directory as the file.
+    ///
+    /// The path is removed along with the file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use assert_fs::fixture::NamedTempFile;
+    ///
+    /// let tmp_file = NamedTempFile::new("foo.rs").unwrap();
+    ///
+    /// println!("{}", tmp_file.sibling("foo.rs.bak"));
+    ///
+    /// // Ensure deletion happens.
+    /// tmp_file.close().unwrap();
+    /// ```
+    pub fn sibling<P>(&self, name: P) -> ChildPath
+    where
+        P: AsRef<path::Path>,
+    {
+        let dir = self.path.parent().expect("within a temporary directory");
+        ChildPath::in_root(dir, name.as_ref())
+    }
+
     /// Closes and removes the temporary file and parent directory, returing a `Result`.
     ///
     /// Although `NamedTempFile` removes the directory on drop, in the destructor
@@ -194,11 +248,39 @@ impl NamedTempFile {
     /// ```
     pub fn close(self) -> Result<(), FixtureError> {
         match self.temp {
-            Inner::Temp(temp) => temp
-                .close()
-                .chain(FixtureError::new(FixtureKind::Cleanup))?,
+            Inner::Temp(temp) => {
+                let path = temp.path().to_owned();
+                temp.close()
+                    .chain_with(|| FixtureError::new(FixtureKind::Cleanup).with_target_path(path))?
+            }
             Inner::Persisted => (),
         }
         Ok(())
     }
 }
+
+impl AsRef<path::Path> for NamedTempFile {
+    fn as_ref(&self) -> &path::Path {
+        self.path()
+    }
+}
+
+impl AsRef<ffi::OsStr> for NamedTempFile {
+    fn as_ref(&self) -> &ffi::OsStr {
+        self.path().as_os_str()
+    }
+}
+
+impl ops::Deref for NamedTempFile {
+    type Target = path::Path;
+
+    fn deref(&self) -> &path::Path {
+        self.path()
+    }
+}
+
+impl fmt::Display for NamedTempFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.path().display().fmt(f)
+    }
+}
@@ -1,13 +1,27 @@
 //! Initialize the filesystem to use as test fixtures.
 
 mod child;
+mod cwd;
 mod dir;
+mod env;
 mod errors;
+#[cfg(any(feature = "tar", feature = "zip"))]
+mod extract;
 mod file;
+mod snapshot;
+mod template;
 mod tools;
+mod txtar;
 
 pub use self::child::*;
+pub use self::cwd::*;
 pub use self::dir::*;
+pub use self::env::*;
 pub use self::errors::*;
+#[cfg(any(feature = "tar", feature = "zip"))]
+pub use self::extract::*;
 pub use self::file::*;
+pub use self::snapshot::*;
+pub use self::template::*;
 pub use self::tools::*;
+pub use self::txtar::*;
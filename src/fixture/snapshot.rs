@@ -0,0 +1,383 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::path;
+use std::time;
+
+use walkdir;
+
+use super::errors::*;
+use super::ChildPath;
+use super::TempDir;
+use crate::digest;
+use crate::walk;
+
+/// Record the state of a directory tree so it can later be compared.
+///
+/// See [`Snapshot::diff`].
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// temp.child("a.txt").write_str("a").unwrap();
+/// let before = temp.snapshot().unwrap();
+///
+/// temp.child("a.txt").write_str("b").unwrap();
+///
+/// let after = temp.snapshot().unwrap();
+/// before.diff(&after).assert_only_modified(&["a.txt"]);
+/// temp.close().unwrap();
+/// ```
+///
+/// [`Snapshot::diff`]: struct.Snapshot.html#method.diff
+pub trait PathSnapshot {
+    /// Record every entry under the current path.
+    fn snapshot(&self) -> Result<Snapshot, FixtureError>;
+}
+
+impl PathSnapshot for TempDir {
+    fn snapshot(&self) -> Result<Snapshot, FixtureError> {
+        Snapshot::new(self.path())
+    }
+}
+
+impl PathSnapshot for ChildPath {
+    fn snapshot(&self) -> Result<Snapshot, FixtureError> {
+        Snapshot::new(self.path())
+    }
+}
+
+/// The state of a directory tree at a point in time.
+///
+/// Created by [`PathSnapshot::snapshot`].
+///
+/// [`PathSnapshot::snapshot`]: trait.PathSnapshot.html#tymethod.snapshot
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+    root: path::PathBuf,
+    entries: BTreeMap<path::PathBuf, SnapshotEntry>,
+}
+
+impl Snapshot {
+    fn new(root: &path::Path) -> Result<Self, FixtureError> {
+        let mut entries = BTreeMap::new();
+        for entry in walk::entries(root) {
+            let (rel, entry) =
+                entry.chain_with(|| FixtureError::new(FixtureKind::Walk).with_source_path(root))?;
+            entries.insert(rel, SnapshotEntry::new(&entry)?);
+        }
+        Ok(Self {
+            root: root.to_owned(),
+            entries,
+        })
+    }
+
+    /// The path the snapshot was taken of.
+    pub fn root(&self) -> &path::Path {
+        &self.root
+    }
+
+    /// Look up an entry by its path relative to [`root`].
+    ///
+    /// [`root`]: #method.root
+    pub fn get<P>(&self, path: P) -> Option<&SnapshotEntry>
+    where
+        P: AsRef<path::Path>,
+    {
+        self.entries.get(path.as_ref())
+    }
+
+    /// Iterate over all entries, sorted by their path relative to [`root`].
+    ///
+    /// [`root`]: #method.root
+    pub fn iter(&self) -> impl Iterator<Item = (&path::Path, &SnapshotEntry)> {
+        self.entries.iter().map(|(p, e)| (p.as_path(), e))
+    }
+
+    /// Compare against a later snapshot of the same tree.
+    ///
+    /// Directories are only reported as modified when their type or mode changes, so creating a
+    /// file does not also report its parent directory.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let before = temp.snapshot().unwrap();
+    ///
+    /// temp.child("out/a.txt").touch().unwrap();
+    ///
+    /// let changes = before.diff(&temp.snapshot().unwrap());
+    /// changes.assert_only_created(&["out", "out/a.txt"]);
+    /// temp.close().unwrap();
+    /// ```
+    pub fn diff(&self, after: &Snapshot) -> Changes {
+        let mut changes = Changes::default();
+        for (path, before) in &self.entries {
+            match after.entries.get(path) {
+                Some(after) => {
+                    if before.is_modified(after) {
+                        changes.modified.insert(path.clone());
+                    }
+                }
+                None => {
+                    changes.deleted.insert(path.clone());
+                }
+            }
+        }
+        for path in after.entries.keys() {
+            if !self.entries.contains_key(path) {
+                changes.created.insert(path.clone());
+            }
+        }
+        changes
+    }
+}
+
+/// The type of a [`SnapshotEntry`].
+///
+/// [`SnapshotEntry`]: struct.SnapshotEntry.html
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EntryType {
+    /// A regular file.
+    File,
+    /// A directory.
+    Dir,
+    /// A symbolic link.
+    Symlink,
+    /// Anything else, like a FIFO or socket.
+    Other,
+}
+
+impl fmt::Display for EntryType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            EntryType::File => write!(f, "file"),
+            EntryType::Dir => write!(f, "dir"),
+            EntryType::Symlink => write!(f, "symlink"),
+            EntryType::Other => write!(f, "other"),
+        }
+    }
+}
+
+/// The recorded state of a single path in a [`Snapshot`].
+///
+/// [`Snapshot`]: struct.Snapshot.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotEntry {
+    file_type: EntryType,
+    size: u64,
+    mode: u32,
+    modified: Option<time::SystemTime>,
+    digest: Option<String>,
+}
+
+impl SnapshotEntry {
+    fn new(entry: &walkdir::DirEntry) -> Result<Self, FixtureError> {
+        let metadata = entry.metadata().chain_with(|| {
+            FixtureError::new(FixtureKind::Metadata).with_source_path(entry.path())
+        })?;
+        let file_type = entry.file_type();
+        let file_type = if file_type.is_file() {
+            EntryType::File
+        } else if file_type.is_dir() {
+            EntryType::Dir
+        } else if file_type.is_symlink() {
+            EntryType::Symlink
+        } else {
+            EntryType::Other
+        };
+        let digest = match file_type {
+            EntryType::File => Some(
+                digest::file_digest(digest::Algorithm::Sha256, entry.path()).chain_with(|| {
+                    FixtureError::new(FixtureKind::ReadFile).with_source_path(entry.path())
+                })?,
+            ),
+            EntryType::Symlink => {
+                let target = fs::read_link(entry.path()).chain_with(|| {
+                    FixtureError::new(FixtureKind::ReadFile).with_source_path(entry.path())
+                })?;
+                let target = target.to_string_lossy();
+                Some(
+                    digest::read_digest(digest::Algorithm::Sha256, target.as_bytes())
+                        .chain(FixtureError::new(FixtureKind::ReadFile))?,
+                )
+            }
+            EntryType::Dir | EntryType::Other => None,
+        };
+        Ok(Self {
+            file_type,
+            size: metadata.len(),
+            mode: mode(&metadata),
+            modified: metadata.modified().ok(),
+            digest,
+        })
+    }
+
+    /// The type of the entry.
+    pub fn file_type(&self) -> EntryType {
+        self.file_type
+    }
+
+    /// Size in bytes, as reported by the filesystem.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Permission bits.
+    ///
+    /// On non-Unix platforms, this is `0o444` for read-only entries and `0o666` otherwise.
+    pub fn mode(&self) -> u32 {
+        self.mode
+    }
+
+    /// Last modification time, if supported by the platform.
+    pub fn modified(&self) -> Option<time::SystemTime> {
+        self.modified
+    }
+
+    /// Hex-encoded SHA-256 digest of a file's content or a symlink's target path.
+    pub fn digest(&self) -> Option<&str> {
+        self.digest.as_deref()
+    }
+
+    fn is_modified(&self, after: &SnapshotEntry) -> bool {
+        if self.file_type == EntryType::Dir && after.file_type == EntryType::Dir {
+            self.mode != after.mode
+        } else {
+            self != after
+        }
+    }
+}
+
+#[cfg(unix)]
+fn mode(metadata: &fs::Metadata) -> u32 {
+    use std::os::unix::fs::PermissionsExt;
+    metadata.permissions().mode() & 0o7777
+}
+
+#[cfg(not(unix))]
+fn mode(metadata: &fs::Metadata) -> u32 {
+    if metadata.permissions().readonly() {
+        0o444
+    } else {
+        0o666
+    }
+}
+
+/// Differences between two [`Snapshot`]s.
+///
+/// Created by [`Snapshot::diff`].  All paths are relative to the snapshot root.
+///
+/// [`Snapshot`]: struct.Snapshot.html
+/// [`Snapshot::diff`]: struct.Snapshot.html#method.diff
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Changes {
+    created: BTreeSet<path::PathBuf>,
+    deleted: BTreeSet<path::PathBuf>,
+    modified: BTreeSet<path::PathBuf>,
+}
+
+impl Changes {
+    /// Paths that only exist in the later snapshot.
+    pub fn created(&self) -> impl Iterator<Item = &path::Path> {
+        self.created.iter().map(|p| p.as_path())
+    }
+
+    /// Paths that only exist in the earlier snapshot.
+    pub fn deleted(&self) -> impl Iterator<Item = &path::Path> {
+        self.deleted.iter().map(|p| p.as_path())
+    }
+
+    /// Paths that exist in both snapshots but differ.
+    pub fn modified(&self) -> impl Iterator<Item = &path::Path> {
+        self.modified.iter().map(|p| p.as_path())
+    }
+
+    /// Whether nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.created.is_empty() && self.deleted.is_empty() && self.modified.is_empty()
+    }
+
+    /// Assert nothing changed.
+    pub fn assert_unchanged(&self) -> &Self {
+        self.assert_exactly(BTreeSet::new(), BTreeSet::new(), BTreeSet::new())
+    }
+
+    /// Assert the only changes are that exactly `paths` were created.
+    pub fn assert_only_created<I, P>(&self, paths: I) -> &Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<path::Path>,
+    {
+        self.assert_exactly(to_set(paths), BTreeSet::new(), BTreeSet::new())
+    }
+
+    /// Assert the only changes are that exactly `paths` were deleted.
+    pub fn assert_only_deleted<I, P>(&self, paths: I) -> &Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<path::Path>,
+    {
+        self.assert_exactly(BTreeSet::new(), to_set(paths), BTreeSet::new())
+    }
+
+    /// Assert the only changes are that exactly `paths` were modified.
+    pub fn assert_only_modified<I, P>(&self, paths: I) -> &Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<path::Path>,
+    {
+        self.assert_exactly(BTreeSet::new(), BTreeSet::new(), to_set(paths))
+    }
+
+    fn assert_exactly(
+        &self,
+        created: BTreeSet<path::PathBuf>,
+        deleted: BTreeSet<path::PathBuf>,
+        modified: BTreeSet<path::PathBuf>,
+    ) -> &Self {
+        let expected = Changes {
+            created,
+            deleted,
+            modified,
+        };
+        if *self != expected {
+            panic!(
+                "Unexpected changes\nexpected:\n{}actual:\n{}",
+                expected, self
+            );
+        }
+        self
+    }
+}
+
+impl fmt::Display for Changes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "  (none)");
+        }
+        for path in &self.created {
+            writeln!(f, "  created: {}", path.display())?;
+        }
+        for path in &self.deleted {
+            writeln!(f, "  deleted: {}", path.display())?;
+        }
+        for path in &self.modified {
+            writeln!(f, "  modified: {}", path.display())?;
+        }
+        Ok(())
+    }
+}
+
+fn to_set<I, P>(paths: I) -> BTreeSet<path::PathBuf>
+where
+    I: IntoIterator<Item = P>,
+    P: AsRef<path::Path>,
+{
+    paths.into_iter().map(|p| p.as_ref().to_owned()).collect()
+}
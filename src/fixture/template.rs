@@ -0,0 +1,188 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::ffi;
+use std::fs;
+use std::io;
+use std::path;
+
+use super::errors::*;
+use super::ChildPath;
+use super::NamedTempFile;
+
+/// Values for `{{name}}` placeholders in fixture templates.
+///
+/// Besides the variables set with [`var`], each file being written provides:
+/// - `{{path}}`: the file's path.
+/// - `{{dir}}`: the directory containing the file.
+/// - `{{name}}`: the file's name.
+///
+/// `{{root}}` is also provided by [`PathCopy::copy_from_with`], as the directory being copied
+/// into, and by [`FileWriteTemplate::write_template`], as the fixture the file is in.
+/// Variables set with [`var`] take precedence.  Whitespace inside the braces is ignored, and an
+/// unknown or empty placeholder is an error.
+///
+/// Write `{{{{` for a literal `{{`.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::fixture::TemplateVars;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let vars = TemplateVars::new()
+///     .var("root", temp.path())
+///     .var("port", "8080");
+/// temp.close().unwrap();
+/// ```
+///
+/// [`var`]: #method.var
+/// [`PathCopy::copy_from_with`]: trait.PathCopy.html#method.copy_from_with
+/// [`FileWriteTemplate::write_template`]: trait.FileWriteTemplate.html#tymethod.write_template
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TemplateVars {
+    vars: BTreeMap<String, String>,
+}
+
+impl TemplateVars {
+    /// No variables beyond the ones provided for each file.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace `{{name}}` with `value`.
+    ///
+    /// Paths are converted lossily to UTF-8.
+    pub fn var<K, V>(mut self, name: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: AsRef<ffi::OsStr>,
+    {
+        self.vars
+            .insert(name.into(), value.as_ref().to_string_lossy().into_owned());
+        self
+    }
+
+    /// Set `name` unless the caller already did.
+    pub(crate) fn var_or<V>(mut self, name: &str, value: V) -> Self
+    where
+        V: AsRef<ffi::OsStr>,
+    {
+        self.vars
+            .entry(name.to_owned())
+            .or_insert_with(|| value.as_ref().to_string_lossy().into_owned());
+        self
+    }
+
+    /// Replace each placeholder in `template` for a file written to `path`.
+    pub(crate) fn render(&self, template: &str, path: &path::Path) -> io::Result<String> {
+        let mut rendered = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find("{{") {
+            rendered.push_str(&rest[..start]);
+            let after = &rest[start + 2..];
+            if let Some(after) = after.strip_prefix("{{") {
+                rendered.push_str("{{");
+                rest = after;
+                continue;
+            }
+            let end = after
+                .find("}}")
+                .ok_or_else(|| invalid_template("unclosed `{{` in template".to_owned()))?;
+            let name = after[..end].trim();
+            if name.is_empty() {
+                return Err(invalid_template(
+                    "empty `{{}}` placeholder in template".to_owned(),
+                ));
+            }
+            let value = self
+                .lookup(name, path)
+                .ok_or_else(|| invalid_template(format!("unknown template variable `{}`", name)))?;
+            rendered.push_str(&value);
+            rest = &after[end + 2..];
+        }
+        rendered.push_str(rest);
+        Ok(rendered)
+    }
+
+    fn lookup<'a>(&'a self, name: &str, path: &'a path::Path) -> Option<Cow<'a, str>> {
+        if let Some(value) = self.vars.get(name) {
+            return Some(Cow::Borrowed(value));
+        }
+        match name {
+            "path" => Some(path.to_string_lossy()),
+            "dir" => path.parent().map(|p| p.to_string_lossy()),
+            "name" => path.file_name().map(|n| n.to_string_lossy()),
+            _ => None,
+        }
+    }
+}
+
+fn invalid_template(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Write a text file at [`ChildPath`], filling in `{{name}}` placeholders.
+///
+/// See [`TemplateVars`] for the available placeholders.
+///
+/// [`ChildPath`]: struct.ChildPath.html
+/// [`TemplateVars`]: struct.TemplateVars.html
+pub trait FileWriteTemplate {
+    /// Write `template` to [`ChildPath`], replacing its placeholders with `vars`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::fixture::TemplateVars;
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let vars = TemplateVars::new().var("port", "8080");
+    /// let config = temp.child("config.toml");
+    /// config
+    ///     .write_template("data = \"{{root}}/data\"\nport = {{port}}\n", &vars)
+    ///     .unwrap();
+    /// config.assert(format!(
+    ///     "data = \"{}/data\"\nport = 8080\n",
+    ///     temp.path().display()
+    /// ));
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    /// [`ChildPath`]: struct.ChildPath.html
+    fn write_template(&self, template: &str, vars: &TemplateVars) -> Result<(), FixtureError>;
+}
+
+impl FileWriteTemplate for ChildPath {
+    fn write_template(&self, template: &str, vars: &TemplateVars) -> Result<(), FixtureError> {
+        match self.root() {
+            Some(root) => write_template(self.path(), template, &vars.clone().var_or("root", root)),
+            None => write_template(self.path(), template, vars),
+        }
+    }
+}
+
+impl FileWriteTemplate for NamedTempFile {
+    fn write_template(&self, template: &str, vars: &TemplateVars) -> Result<(), FixtureError> {
+        let dir = self.path().parent().expect("within a temporary directory");
+        let vars = vars.clone().var_or("root", dir);
+        write_template(self.path(), template, &vars)
+    }
+}
+
+fn write_template(
+    path: &path::Path,
+    template: &str,
+    vars: &TemplateVars,
+) -> Result<(), FixtureError> {
+    let rendered = vars
+        .render(template, path)
+        .chain_with(|| FixtureError::new(FixtureKind::WriteFile).with_target_path(path))?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .chain_with(|| FixtureError::new(FixtureKind::CreateDir).with_target_path(parent))?;
+    }
+    fs::write(path, rendered)
+        .chain_with(|| FixtureError::new(FixtureKind::WriteFile).with_target_path(path))?;
+    Ok(())
+}
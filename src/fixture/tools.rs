@@ -1,15 +1,22 @@
 //! Initialize the filesystem to use as test fixtures.
 
+use std::cmp;
 use std::fs;
+use std::io;
 use std::io::Write;
 use std::path;
+use std::sync;
+use std::sync::atomic;
+use std::thread;
 
-use globwalk;
+use crate::digest;
+use crate::walk;
 
 use super::errors::*;
 use super::ChildPath;
 use super::NamedTempFile;
 use super::TempDir;
+use super::TemplateVars;
 
 /// Create empty directories at [`ChildPath`].
 ///
@@ -139,6 +146,112 @@ impl FileWriteStr for NamedTempFile {
     }
 }
 
+/// Write a text file at [`ChildPath`], one line at a time.
+///
+/// [`ChildPath`]: struct.ChildPath.html
+pub trait FileWriteLines {
+    /// Write a text file at [`ChildPath`], ending each of `lines` with `\n`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let log = temp.child("app.log");
+    /// log.write_lines(&["starting", "ready"]).unwrap();
+    /// log.assert("starting\nready\n");
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    /// [`ChildPath`]: struct.ChildPath.html
+    fn write_lines<I, S>(&self, lines: I) -> Result<(), FixtureError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>;
+}
+
+impl FileWriteLines for ChildPath {
+    fn write_lines<I, S>(&self, lines: I) -> Result<(), FixtureError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        write_lines(self.path(), lines)
+    }
+}
+
+impl FileWriteLines for NamedTempFile {
+    fn write_lines<I, S>(&self, lines: I) -> Result<(), FixtureError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        write_lines(self.path(), lines)
+    }
+}
+
+/// Append to a file at [`ChildPath`], creating it if needed.
+///
+/// [`ChildPath`]: struct.ChildPath.html
+pub trait FileAppend {
+    /// Append binary data to the file at [`ChildPath`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let data = temp.child("data.bin");
+    /// data.append_binary(b"\x00\x01").unwrap();
+    /// data.append_binary(b"\x02").unwrap();
+    /// data.assert(b"\x00\x01\x02" as &[u8]);
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    /// [`ChildPath`]: struct.ChildPath.html
+    fn append_binary(&self, data: &[u8]) -> Result<(), FixtureError>;
+
+    /// Append text to the file at [`ChildPath`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let log = temp.child("app.log");
+    /// log.append_str("starting\n").unwrap();
+    /// log.append_str("ready\n").unwrap();
+    /// log.assert("starting\nready\n");
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    /// [`ChildPath`]: struct.ChildPath.html
+    fn append_str(&self, data: &str) -> Result<(), FixtureError>;
+}
+
+impl FileAppend for ChildPath {
+    fn append_binary(&self, data: &[u8]) -> Result<(), FixtureError> {
+        append_binary(self.path(), data)
+    }
+
+    fn append_str(&self, data: &str) -> Result<(), FixtureError> {
+        append_binary(self.path(), data.as_bytes())
+    }
+}
+
+impl FileAppend for NamedTempFile {
+    fn append_binary(&self, data: &[u8]) -> Result<(), FixtureError> {
+        append_binary(self.path(), data)
+    }
+
+    fn append_str(&self, data: &str) -> Result<(), FixtureError> {
+        append_binary(self.path(), data.as_bytes())
+    }
+}
+
 /// Write (copy) a file to [`ChildPath`].
 ///
 /// [`ChildPath`]: struct.ChildPath.html
@@ -175,6 +288,146 @@ impl FileWriteFile for NamedTempFile {
     }
 }
 
+/// Remove files and directories at [`ChildPath`].
+///
+/// [`ChildPath`]: struct.ChildPath.html
+pub trait PathRemove {
+    /// Remove the file, symlink, or empty directory at [`ChildPath`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    /// use predicates::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let input_file = temp.child("foo.txt");
+    /// input_file.touch().unwrap();
+    /// input_file.remove().unwrap();
+    /// input_file.assert(predicate::path::missing());
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    /// [`ChildPath`]: struct.ChildPath.html
+    fn remove(&self) -> Result<(), FixtureError>;
+
+    /// Remove the directory at [`ChildPath`], after removing all of its contents.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    /// use predicates::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let subdir = temp.child("subdir");
+    /// subdir.child("foo.txt").touch().unwrap();
+    /// subdir.remove_dir_all().unwrap();
+    /// subdir.assert(predicate::path::missing());
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    /// [`ChildPath`]: struct.ChildPath.html
+    fn remove_dir_all(&self) -> Result<(), FixtureError>;
+}
+
+impl PathRemove for ChildPath {
+    fn remove(&self) -> Result<(), FixtureError> {
+        remove(self.path())
+    }
+
+    fn remove_dir_all(&self) -> Result<(), FixtureError> {
+        fs::remove_dir_all(self.path())
+            .chain_with(|| FixtureError::new(FixtureKind::Remove).with_target_path(self.path()))
+    }
+}
+
+/// Rename and move files and directories at [`ChildPath`].
+///
+/// [`ChildPath`]: struct.ChildPath.html
+pub trait PathRename {
+    /// Rename [`ChildPath`] to `to`, returning the new path.
+    ///
+    /// A relative `to` is relative to the directory containing [`ChildPath`], so a bare file name
+    /// renames within the same directory.  An existing file at `to` is replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    /// use predicates::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let input_file = temp.child("foo.txt");
+    /// input_file.write_str("content").unwrap();
+    /// let renamed = input_file.rename_to("bar.txt").unwrap();
+    /// input_file.assert(predicate::path::missing());
+    /// renamed.assert("content");
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    /// [`ChildPath`]: struct.ChildPath.html
+    fn rename_to<P>(&self, to: P) -> Result<ChildPath, FixtureError>
+    where
+        P: AsRef<path::Path>;
+
+    /// Move [`ChildPath`] into the directory `dir`, keeping its name and returning the new path.
+    ///
+    /// `dir` is created if it doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let input_file = temp.child("foo.txt");
+    /// input_file.write_str("content").unwrap();
+    /// let moved = input_file.move_into(temp.child("archive")).unwrap();
+    /// assert_eq!(moved.path(), temp.child("archive/foo.txt").path());
+    /// moved.assert("content");
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    /// [`ChildPath`]: struct.ChildPath.html
+    fn move_into<P>(&self, dir: P) -> Result<ChildPath, FixtureError>
+    where
+        P: AsRef<path::Path>;
+}
+
+impl PathRename for ChildPath {
+    fn rename_to<P>(&self, to: P) -> Result<ChildPath, FixtureError>
+    where
+        P: AsRef<path::Path>,
+    {
+        let to = match self.path().parent() {
+            Some(parent) => parent.join(to.as_ref()),
+            None => to.as_ref().to_owned(),
+        };
+        rename(self.path(), &to)?;
+        Ok(self.with_path(to))
+    }
+
+    fn move_into<P>(&self, dir: P) -> Result<ChildPath, FixtureError>
+    where
+        P: AsRef<path::Path>,
+    {
+        let dir = dir.as_ref();
+        let name = self.path().file_name().ok_or_else(|| {
+            FixtureError::new(FixtureKind::Rename)
+                .with_source_path(self.path())
+                .chain(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "path has no file name",
+                ))
+        })?;
+        create_dir_all(dir)?;
+        let to = dir.join(name);
+        rename(self.path(), &to)?;
+        Ok(self.with_path(to))
+    }
+}
+
 /// Copy files into [`TempDir`].
 ///
 /// [`TempDir`]: struct.TempDir.html
@@ -195,6 +448,39 @@ pub trait PathCopy {
     where
         P: AsRef<path::Path>,
         S: AsRef<str>;
+
+    /// Copy files and directories into the current path from the `source` according to the glob
+    /// `patterns`, customized by `options`.
+    ///
+    /// Returns a [`CopyReport`] of what was copied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::fixture::CopyOptions;
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let options = CopyOptions::new().exclude("target").exclude(".git");
+    /// let report = temp.copy_from_with(".", &["**/*.rs"], &options).unwrap();
+    /// assert!(report.files().count() > 0);
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    /// [`CopyReport`]: struct.CopyReport.html
+    fn copy_from_with<P, S>(
+        &self,
+        source: P,
+        patterns: &[S],
+        options: &CopyOptions,
+    ) -> Result<CopyReport, FixtureError>
+    where
+        P: AsRef<path::Path>,
+        S: AsRef<str>,
+        Self: AsRef<path::Path>,
+    {
+        copy_files(self.as_ref(), source.as_ref(), patterns, options)
+    }
 }
 
 impl PathCopy for TempDir {
@@ -203,7 +489,13 @@ impl PathCopy for TempDir {
         P: AsRef<path::Path>,
         S: AsRef<str>,
     {
-        copy_files(self.path(), source.as_ref(), patterns)
+        copy_files(
+            self.path(),
+            source.as_ref(),
+            patterns,
+            &CopyOptions::default(),
+        )
+        .map(|_| ())
     }
 }
 
@@ -213,44 +505,701 @@ impl PathCopy for ChildPath {
         P: AsRef<path::Path>,
         S: AsRef<str>,
     {
-        copy_files(self.path(), source.as_ref(), patterns)
+        copy_files(
+            self.path(),
+            source.as_ref(),
+            patterns,
+            &CopyOptions::default(),
+        )
+        .map(|_| ())
     }
 }
 
+/// What [`PathCopy::copy_from_with`] does when a file already exists at the target.
+///
+/// [`PathCopy::copy_from_with`]: trait.PathCopy.html#method.copy_from_with
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Overwrite {
+    /// Replace the existing file.
+    #[default]
+    Always,
+    /// Leave the existing file untouched.
+    Skip,
+    /// Fail with [`FixtureKind::CopyFile`].
+    ///
+    /// [`FixtureKind::CopyFile`]: enum.FixtureKind.html#variant.CopyFile
+    Error,
+}
+
+/// What [`PathCopy::copy_from_with`] does with entries that are not files, directories, or
+/// symlinks, like FIFOs and sockets.
+///
+/// [`PathCopy::copy_from_with`]: trait.PathCopy.html#method.copy_from_with
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SpecialFiles {
+    /// Leave them out of the copy.
+    #[default]
+    Skip,
+    /// Fail with [`FixtureKind::CopyFile`].
+    ///
+    /// [`FixtureKind::CopyFile`]: enum.FixtureKind.html#variant.CopyFile
+    Error,
+}
+
+/// How [`PathCopy::copy_from_with`] creates the content of copied files.
+///
+/// [`PathCopy::copy_from_with`]: trait.PathCopy.html#method.copy_from_with
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Materialize {
+    /// Copy the content.
+    #[default]
+    Copy,
+    /// Share the content through a copy-on-write reflink where the filesystem supports it,
+    /// falling back to copying.
+    ///
+    /// Reflinks are currently only attempted on Linux (`FICLONE`, e.g. btrfs or XFS).
+    Reflink,
+    /// Like [`Reflink`], but falls back to hard links for read-only source files before copying.
+    ///
+    /// A hard linked file shares its inode with the source: changing its permissions, or writing
+    /// to it in place after making it writable, changes the source too.  This is why only
+    /// read-only files are linked.  Modification times are never applied to hard links.
+    ///
+    /// [`Reflink`]: #variant.Reflink
+    ReflinkOrHardlink,
+}
+
+/// Options for [`PathCopy::copy_from_with`].
+///
+/// The defaults match [`PathCopy::copy_from`].
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::fixture::CopyOptions;
+/// use assert_fs::fixture::Overwrite;
+///
+/// let options = CopyOptions::new()
+///     .exclude("target")
+///     .max_depth(3)
+///     .follow_links(false)
+///     .overwrite(Overwrite::Error)
+///     .hidden(false);
+/// ```
+///
+/// [`PathCopy::copy_from`]: trait.PathCopy.html#tymethod.copy_from
+/// [`PathCopy::copy_from_with`]: trait.PathCopy.html#method.copy_from_with
+#[derive(Clone, Debug)]
+pub struct CopyOptions {
+    excludes: Vec<String>,
+    max_depth: Option<usize>,
+    follow_links: bool,
+    overwrite: Overwrite,
+    hidden: bool,
+    preserve_permissions: bool,
+    preserve_mtime: bool,
+    special_files: SpecialFiles,
+    require_match: bool,
+    gitignore: bool,
+    materialize: Materialize,
+    threads: usize,
+    template: Option<TemplateVars>,
+    template_files: Vec<String>,
+}
+
+impl CopyOptions {
+    /// Default copy options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reproduce the source tree as closely as possible.
+    ///
+    /// Symlinks are recreated rather than followed, permissions and modification times are
+    /// preserved, and special files are an error rather than being skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::fixture::CopyOptions;
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// temp.copy_from_with("tests/fixture", &["**"], &CopyOptions::faithful())
+    ///     .unwrap();
+    /// temp.close().unwrap();
+    /// ```
+    pub fn faithful() -> Self {
+        Self::default()
+            .follow_links(false)
+            .preserve_permissions(true)
+            .preserve_mtime(true)
+            .special_files(SpecialFiles::Error)
+    }
+
+    /// Skip entries matching the glob `pattern`.
+    ///
+    /// Patterns follow the same rules as the `patterns` passed to `copy_from_with`.  Excluding a
+    /// directory skips everything within it.
+    pub fn exclude<S>(mut self, pattern: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.excludes.push(pattern.into());
+        self
+    }
+
+    /// Only descend `depth` levels below the source.
+    ///
+    /// A depth of `1` only copies the immediate children of the source.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Copy what symlinks point to (the default) rather than recreating the symlinks.
+    pub fn follow_links(mut self, yes: bool) -> Self {
+        self.follow_links = yes;
+        self
+    }
+
+    /// Control what happens when a file already exists at the target.
+    pub fn overwrite(mut self, overwrite: Overwrite) -> Self {
+        self.overwrite = overwrite;
+        self
+    }
+
+    /// Include hidden files and directories (the default), i.e. those starting with `.`.
+    pub fn hidden(mut self, yes: bool) -> Self {
+        self.hidden = yes;
+        self
+    }
+
+    /// Apply the source's permissions to copied directories.
+    ///
+    /// Files always keep their permissions.
+    pub fn preserve_permissions(mut self, yes: bool) -> Self {
+        self.preserve_permissions = yes;
+        self
+    }
+
+    /// Apply the source's modification times to the copied entries.
+    pub fn preserve_mtime(mut self, yes: bool) -> Self {
+        self.preserve_mtime = yes;
+        self
+    }
+
+    /// Control what happens to FIFOs, sockets, and other special files.
+    pub fn special_files(mut self, special_files: SpecialFiles) -> Self {
+        self.special_files = special_files;
+        self
+    }
+
+    /// Fail with [`FixtureKind::Walk`] if the patterns matched no files.
+    ///
+    /// [`FixtureKind::Walk`]: enum.FixtureKind.html#variant.Walk
+    pub fn require_match(mut self, yes: bool) -> Self {
+        self.require_match = yes;
+        self
+    }
+
+    /// Skip entries ignored by `.gitignore` and `.ignore` files within the source.
+    ///
+    /// Only ignore files inside the source tree are consulted; global git configuration and
+    /// parent directories are not.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::fixture::CopyOptions;
+    /// use assert_fs::prelude::*;
+    ///
+    /// let source = assert_fs::TempDir::new().unwrap();
+    /// source.child(".gitignore").write_str("target/\n").unwrap();
+    /// source.child("src/lib.rs").touch().unwrap();
+    /// source.child("target/debug/lib.rlib").touch().unwrap();
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let options = CopyOptions::new().gitignore(true);
+    /// temp.copy_from_with(source.path(), &["**"], &options).unwrap();
+    /// temp.child("src/lib.rs").assert(predicates::path::exists());
+    /// temp.child("target").assert(predicates::path::missing());
+    ///
+    /// temp.close().unwrap();
+    /// source.close().unwrap();
+    /// ```
+    pub fn gitignore(mut self, yes: bool) -> Self {
+        self.gitignore = yes;
+        self
+    }
+
+    /// Control how file content is created, e.g. to avoid copying large fixtures.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::fixture::CopyOptions;
+    /// use assert_fs::fixture::Materialize;
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let options = CopyOptions::new().materialize(Materialize::Reflink);
+    /// temp.copy_from_with("tests/fixture", &["*.txt"], &options)
+    ///     .unwrap();
+    /// temp.child("hello.txt").assert("hello\n");
+    /// temp.close().unwrap();
+    /// ```
+    pub fn materialize(mut self, materialize: Materialize) -> Self {
+        self.materialize = materialize;
+        self
+    }
+
+    /// Copy files on up to `threads` threads.
+    ///
+    /// The default is `1`, copying serially.  `0` uses one thread per available CPU.  Failures
+    /// are reported for the first failing entry in walk order, like a serial copy.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::fixture::CopyOptions;
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let options = CopyOptions::new().threads(4);
+    /// temp.copy_from_with(".", &["src/**"], &options).unwrap();
+    /// temp.close().unwrap();
+    /// ```
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Fill in `{{name}}` placeholders in copied text files with `vars`.
+    ///
+    /// `{{root}}` is the directory being copied into, unless `vars` sets it.  Files that are not
+    /// UTF-8 are copied unchanged, but an unknown placeholder in a text file fails the copy, so
+    /// use [`template_files`] when other text files may contain `{{`.  See [`TemplateVars`] for
+    /// the other placeholders.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::fixture::CopyOptions;
+    /// use assert_fs::fixture::TemplateVars;
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let options = CopyOptions::new().template(TemplateVars::new().var("port", "8080"));
+    /// temp.copy_from_with("tests/fixture", &["*.txt"], &options)
+    ///     .unwrap();
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    /// [`template_files`]: #method.template_files
+    /// [`TemplateVars`]: struct.TemplateVars.html
+    pub fn template(mut self, vars: TemplateVars) -> Self {
+        self.template = Some(vars);
+        self
+    }
+
+    /// Only fill in placeholders for files matching the glob `pattern`.
+    ///
+    /// Patterns follow the same rules as the `patterns` passed to `copy_from_with`.  Once any
+    /// pattern is given, other files are copied unchanged.  This has no effect without
+    /// [`template`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::fixture::CopyOptions;
+    /// use assert_fs::fixture::TemplateVars;
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let options = CopyOptions::new()
+    ///     .template(TemplateVars::new().var("port", "8080"))
+    ///     .template_files("*.toml");
+    /// temp.copy_from_with("tests/fixture", &["**"], &options)
+    ///     .unwrap();
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    /// [`template`]: #method.template
+    pub fn template_files<S>(mut self, pattern: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.template_files.push(pattern.into());
+        self
+    }
+}
+
+/// What [`PathCopy::copy_from_with`] did.
+///
+/// All paths are relative to the copy's target.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::fixture::CopyOptions;
+/// use assert_fs::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let report = temp
+///     .copy_from_with("tests/fixture", &["*.txt"], &CopyOptions::new())
+///     .unwrap();
+/// assert_eq!(
+///     report.files().collect::<Vec<_>>(),
+///     [std::path::Path::new("hello.txt")]
+/// );
+/// assert_eq!(report.bytes(), 6);
+/// temp.close().unwrap();
+/// ```
+///
+/// [`PathCopy::copy_from_with`]: trait.PathCopy.html#method.copy_from_with
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CopyReport {
+    files: Vec<path::PathBuf>,
+    dirs: Vec<path::PathBuf>,
+    skipped: Vec<path::PathBuf>,
+    bytes: u64,
+}
+
+impl CopyReport {
+    /// Files and symlinks that were copied.
+    pub fn files(&self) -> impl Iterator<Item = &path::Path> {
+        self.files.iter().map(|p| p.as_path())
+    }
+
+    /// Directories that were created, whether they matched or hold a matched entry.
+    ///
+    /// Directories that already existed are not included.
+    pub fn dirs(&self) -> impl Iterator<Item = &path::Path> {
+        self.dirs.iter().map(|p| p.as_path())
+    }
+
+    /// Matched entries that were not copied, because they already existed or are special files.
+    pub fn skipped(&self) -> impl Iterator<Item = &path::Path> {
+        self.skipped.iter().map(|p| p.as_path())
+    }
+
+    /// Total size of the copied files.
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// Whether nothing was copied or created.
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty() && self.dirs.is_empty()
+    }
+}
+
+impl Default for CopyOptions {
+    fn default() -> Self {
+        Self {
+            excludes: Vec::new(),
+            max_depth: None,
+            follow_links: true,
+            overwrite: Overwrite::default(),
+            hidden: true,
+            preserve_permissions: false,
+            preserve_mtime: false,
+            special_files: SpecialFiles::default(),
+            require_match: false,
+            gitignore: false,
+            materialize: Materialize::default(),
+            threads: 1,
+            template: None,
+            template_files: Vec::new(),
+        }
+    }
+}
+
+/// Checksum the content of a [`ChildPath`] or [`NamedTempFile`].
+///
+/// [`ChildPath`]: struct.ChildPath.html
+/// [`NamedTempFile`]: struct.NamedTempFile.html
+pub trait PathDigest {
+    /// Hex-encoded SHA-256 digest of the file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let input_file = temp.child("foo.txt");
+    /// input_file.touch().unwrap();
+    /// assert_eq!(
+    ///     input_file.sha256().unwrap(),
+    ///     "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    /// );
+    /// temp.close().unwrap();
+    /// ```
+    fn sha256(&self) -> Result<String, FixtureError>;
+
+    /// Hex-encoded BLAKE3 digest of the file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let input_file = temp.child("foo.txt");
+    /// input_file.touch().unwrap();
+    /// assert_eq!(
+    ///     input_file.blake3().unwrap(),
+    ///     "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+    /// );
+    /// temp.close().unwrap();
+    /// ```
+    fn blake3(&self) -> Result<String, FixtureError>;
+
+    /// Hex-encoded CRC32 checksum of the file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let input_file = temp.child("foo.txt");
+    /// input_file.touch().unwrap();
+    /// assert_eq!(input_file.crc32().unwrap(), "00000000");
+    /// temp.close().unwrap();
+    /// ```
+    fn crc32(&self) -> Result<String, FixtureError>;
+}
+
+impl PathDigest for ChildPath {
+    fn sha256(&self) -> Result<String, FixtureError> {
+        file_digest(self.path(), digest::Algorithm::Sha256)
+    }
+
+    fn blake3(&self) -> Result<String, FixtureError> {
+        file_digest(self.path(), digest::Algorithm::Blake3)
+    }
+
+    fn crc32(&self) -> Result<String, FixtureError> {
+        file_digest(self.path(), digest::Algorithm::Crc32)
+    }
+}
+
+impl PathDigest for NamedTempFile {
+    fn sha256(&self) -> Result<String, FixtureError> {
+        file_digest(self.path(), digest::Algorithm::Sha256)
+    }
+
+    fn blake3(&self) -> Result<String, FixtureError> {
+        file_digest(self.path(), digest::Algorithm::Blake3)
+    }
+
+    fn crc32(&self) -> Result<String, FixtureError> {
+        file_digest(self.path(), digest::Algorithm::Crc32)
+    }
+}
+
+/// Read back the content of a [`ChildPath`] or [`NamedTempFile`].
+///
+/// [`ChildPath`]: struct.ChildPath.html
+/// [`NamedTempFile`]: struct.NamedTempFile.html
+pub trait FileRead {
+    /// Read the file as UTF-8 text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let input_file = temp.child("foo.txt");
+    /// input_file.write_str("Hello World").unwrap();
+    /// assert_eq!(input_file.read_to_string().unwrap(), "Hello World");
+    /// temp.close().unwrap();
+    /// ```
+    fn read_to_string(&self) -> Result<String, FixtureError>;
+
+    /// Read the file as bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let input_file = temp.child("foo.bin");
+    /// input_file.write_binary(b"\x00\x01").unwrap();
+    /// assert_eq!(input_file.read_bytes().unwrap(), b"\x00\x01");
+    /// temp.close().unwrap();
+    /// ```
+    fn read_bytes(&self) -> Result<Vec<u8>, FixtureError>;
+}
+
+impl FileRead for ChildPath {
+    fn read_to_string(&self) -> Result<String, FixtureError> {
+        fs::read_to_string(self.path())
+            .chain_with(|| FixtureError::new(FixtureKind::ReadFile).with_source_path(self.path()))
+    }
+
+    fn read_bytes(&self) -> Result<Vec<u8>, FixtureError> {
+        fs::read(self.path())
+            .chain_with(|| FixtureError::new(FixtureKind::ReadFile).with_source_path(self.path()))
+    }
+}
+
+impl FileRead for NamedTempFile {
+    fn read_to_string(&self) -> Result<String, FixtureError> {
+        fs::read_to_string(self.path())
+            .chain_with(|| FixtureError::new(FixtureKind::ReadFile).with_source_path(self.path()))
+    }
+
+    fn read_bytes(&self) -> Result<Vec<u8>, FixtureError> {
+        fs::read(self.path())
+            .chain_with(|| FixtureError::new(FixtureKind::ReadFile).with_source_path(self.path()))
+    }
+}
+
+/// List the entries within a [`TempDir`] or [`ChildPath`].
+///
+/// [`TempDir`]: struct.TempDir.html
+/// [`ChildPath`]: struct.ChildPath.html
+pub trait PathList {
+    /// Every file, directory, and symlink under the current path, recursively.
+    ///
+    /// Paths are relative to the current path and sorted.  Symlinks are not followed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::path::PathBuf;
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// temp.child("b.txt").touch().unwrap();
+    /// temp.child("sub/a.txt").touch().unwrap();
+    /// assert_eq!(
+    ///     temp.list().unwrap(),
+    ///     [PathBuf::from("b.txt"), PathBuf::from("sub"), PathBuf::from("sub/a.txt")]
+    /// );
+    /// temp.close().unwrap();
+    /// ```
+    fn list(&self) -> Result<Vec<path::PathBuf>, FixtureError>;
+}
+
+impl PathList for TempDir {
+    fn list(&self) -> Result<Vec<path::PathBuf>, FixtureError> {
+        list(self.path())
+    }
+}
+
+impl PathList for ChildPath {
+    fn list(&self) -> Result<Vec<path::PathBuf>, FixtureError> {
+        list(self.path())
+    }
+}
+
+fn list(root: &path::Path) -> Result<Vec<path::PathBuf>, FixtureError> {
+    let mut paths = walk::entries(root)
+        .map(|entry| entry.map(|(rel, _)| rel))
+        .collect::<Result<Vec<_>, _>>()
+        .chain_with(|| FixtureError::new(FixtureKind::Walk).with_source_path(root))?;
+    paths.sort();
+    Ok(paths)
+}
+
 fn ensure_parent_dir(path: &path::Path) -> Result<(), FixtureError> {
     if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent).chain(FixtureError::new(FixtureKind::CreateDir))?;
+        fs::create_dir_all(parent)
+            .chain_with(|| FixtureError::new(FixtureKind::CreateDir).with_target_path(parent))?;
     }
     Ok(())
 }
 
 fn create_dir_all(path: &path::Path) -> Result<(), FixtureError> {
-    fs::create_dir_all(path).chain(FixtureError::new(FixtureKind::CreateDir))?;
+    fs::create_dir_all(path)
+        .chain_with(|| FixtureError::new(FixtureKind::CreateDir).with_target_path(path))?;
     Ok(())
 }
 
 fn touch(path: &path::Path) -> Result<(), FixtureError> {
     ensure_parent_dir(path)?;
-    fs::File::create(path).chain(FixtureError::new(FixtureKind::WriteFile))?;
+    fs::File::create(path)
+        .chain_with(|| FixtureError::new(FixtureKind::WriteFile).with_target_path(path))?;
     Ok(())
 }
 
 fn write_binary(path: &path::Path, data: &[u8]) -> Result<(), FixtureError> {
     ensure_parent_dir(path)?;
-    let mut file = fs::File::create(path).chain(FixtureError::new(FixtureKind::WriteFile))?;
+    let mut file = fs::File::create(path)
+        .chain_with(|| FixtureError::new(FixtureKind::WriteFile).with_target_path(path))?;
     file.write_all(data)
-        .chain(FixtureError::new(FixtureKind::WriteFile))?;
+        .chain_with(|| FixtureError::new(FixtureKind::WriteFile).with_target_path(path))?;
     Ok(())
 }
 
 fn write_str(path: &path::Path, data: &str) -> Result<(), FixtureError> {
+    write_binary(path, data.as_bytes())
+}
+
+fn write_lines<I, S>(path: &path::Path, lines: I) -> Result<(), FixtureError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
     ensure_parent_dir(path)?;
-    write_binary(path, data.as_bytes()).chain(FixtureError::new(FixtureKind::WriteFile))
+    let file = fs::File::create(path)
+        .chain_with(|| FixtureError::new(FixtureKind::WriteFile).with_target_path(path))?;
+    let mut file = io::BufWriter::new(file);
+    for line in lines {
+        file.write_all(line.as_ref().as_bytes())
+            .and_then(|()| file.write_all(b"\n"))
+            .chain_with(|| FixtureError::new(FixtureKind::WriteFile).with_target_path(path))?;
+    }
+    file.flush()
+        .chain_with(|| FixtureError::new(FixtureKind::WriteFile).with_target_path(path))?;
+    Ok(())
+}
+
+fn append_binary(path: &path::Path, data: &[u8]) -> Result<(), FixtureError> {
+    ensure_parent_dir(path)?;
+    let mut file = fs::OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(path)
+        .chain_with(|| FixtureError::new(FixtureKind::WriteFile).with_target_path(path))?;
+    file.write_all(data)
+        .chain_with(|| FixtureError::new(FixtureKind::WriteFile).with_target_path(path))?;
+    Ok(())
+}
+
+fn remove(path: &path::Path) -> Result<(), FixtureError> {
+    let metadata = fs::symlink_metadata(path)
+        .chain_with(|| FixtureError::new(FixtureKind::Remove).with_target_path(path))?;
+    if metadata.is_dir() {
+        fs::remove_dir(path)
+    } else {
+        fs::remove_file(path)
+    }
+    .chain_with(|| FixtureError::new(FixtureKind::Remove).with_target_path(path))
+}
+
+fn rename(from: &path::Path, to: &path::Path) -> Result<(), FixtureError> {
+    fs::rename(from, to).chain_with(|| {
+        FixtureError::new(FixtureKind::Rename)
+            .with_source_path(from)
+            .with_target_path(to)
+    })
 }
 
 fn write_file(path: &path::Path, data: &path::Path) -> Result<(), FixtureError> {
     ensure_parent_dir(path)?;
-    fs::copy(data, path).chain(FixtureError::new(FixtureKind::CopyFile))?;
+    fs::copy(data, path).chain_with(|| {
+        FixtureError::new(FixtureKind::CopyFile)
+            .with_source_path(data)
+            .with_target_path(path)
+    })?;
     Ok(())
 }
 
@@ -258,32 +1207,424 @@ fn copy_files<S>(
     target: &path::Path,
     source: &path::Path,
     patterns: &[S],
-) -> Result<(), FixtureError>
+    options: &CopyOptions,
+) -> Result<CopyReport, FixtureError>
 where
     S: AsRef<str>,
 {
     // `walkdir`, on Windows, seems to convert "." into "" which then fails.
     let source = source
         .canonicalize()
-        .chain(FixtureError::new(FixtureKind::Walk))?;
-    for entry in globwalk::GlobWalkerBuilder::from_patterns(&source, patterns)
-        .follow_links(true)
-        .build()
-        .chain(FixtureError::new(FixtureKind::Walk))?
-    {
-        let entry = entry.chain(FixtureError::new(FixtureKind::Walk))?;
+        .chain_with(|| FixtureError::new(FixtureKind::Walk).with_source_path(source))?;
+    let includes: Vec<_> = patterns.iter().map(|p| p.as_ref().to_owned()).collect();
+    let walk_error = || FixtureError::new(FixtureKind::Walk).with_source_path(&source);
+    // Includes are only matched against entries, like ignore files, since an override whitelist
+    // would take precedence over ignore files.  Excludes are overrides so that excluded
+    // directories aren't walked at all.
+    let matcher =
+        overrides(&source, includes.iter().map(|p| normalize_pattern(p))).chain_with(walk_error)?;
+    let excludes = overrides(&source, options.excludes.iter().map(|p| format!("!{}", p)))
+        .chain_with(walk_error)?;
+    let template_files = overrides(
+        &source,
+        options.template_files.iter().map(|p| normalize_pattern(p)),
+    )
+    .chain_with(walk_error)?;
+    let walker = ignore::WalkBuilder::new(&source)
+        .standard_filters(false)
+        .hidden(!options.hidden)
+        .git_ignore(options.gitignore)
+        .ignore(options.gitignore)
+        .require_git(false)
+        .parents(false)
+        .follow_links(options.follow_links)
+        .max_depth(options.max_depth)
+        .overrides(excludes)
+        .build();
+    let templated;
+    let options = match options.template {
+        Some(ref vars) => {
+            templated = CopyOptions {
+                template: Some(vars.clone().var_or("root", target)),
+                ..options.clone()
+            };
+            &templated
+        }
+        None => options,
+    };
+    let mut report = CopyReport::default();
+    let mut dirs = Vec::new();
+    let mut jobs = Vec::new();
+    for entry in walker {
+        let entry = entry.chain_with(walk_error)?;
         let rel = entry
             .path()
             .strip_prefix(&source)
             .expect("entries to be under `source`");
+        let file_type = match entry.file_type() {
+            Some(file_type) => file_type,
+            // Only stdin has no file type.
+            None => continue,
+        };
+        if rel.as_os_str().is_empty() || !matcher.matched(rel, file_type.is_dir()).is_whitelist() {
+            continue;
+        }
         let target_path = target.join(rel);
-        if entry.file_type().is_dir() {
-            fs::create_dir_all(target_path).chain(FixtureError::new(FixtureKind::CreateDir))?;
-        } else if entry.file_type().is_file() {
-            fs::create_dir_all(target_path.parent().expect("at least `target` exists"))
-                .chain(FixtureError::new(FixtureKind::CreateDir))?;
-            fs::copy(entry.path(), target_path).chain(FixtureError::new(FixtureKind::CopyFile))?;
+        if file_type.is_dir() {
+            create_dirs(target, &target_path, &mut report.dirs)?;
+            dirs.push((entry.path().to_owned(), target_path));
+        } else if file_type.is_file() || file_type.is_symlink() {
+            let parent = target_path.parent().expect("at least `target` exists");
+            create_dirs(target, parent, &mut report.dirs)?;
+            if fs::symlink_metadata(&target_path).is_ok() {
+                match options.overwrite {
+                    Overwrite::Always => {}
+                    Overwrite::Skip => {
+                        report.skipped.push(rel.to_owned());
+                        continue;
+                    }
+                    Overwrite::Error => {
+                        return Err(FixtureError::new(FixtureKind::CopyFile)
+                            .with_source_path(entry.path())
+                            .with_target_path(&target_path)
+                            .chain(io::Error::new(
+                                io::ErrorKind::AlreadyExists,
+                                format!("{} already exists", target_path.display()),
+                            )));
+                    }
+                }
+            }
+            jobs.push(CopyJob {
+                source: entry.path().to_owned(),
+                target: target_path,
+                rel: rel.to_owned(),
+                is_symlink: file_type.is_symlink(),
+                render: options.template.is_some()
+                    && !file_type.is_symlink()
+                    && (template_files.is_empty()
+                        || template_files.matched(rel, false).is_whitelist()),
+            });
+        } else if options.special_files == SpecialFiles::Error {
+            return Err(FixtureError::new(FixtureKind::CopyFile)
+                .with_source_path(entry.path())
+                .chain(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "{} is not a file, directory, or symlink",
+                        entry.path().display()
+                    ),
+                )));
+        } else {
+            report.skipped.push(rel.to_owned());
         }
     }
+    for (job, result) in jobs.iter().zip(run_jobs(&jobs, options)) {
+        let bytes = result
+            .expect("jobs only stop after a failure")
+            .chain_with(|| {
+                let kind = if job.is_symlink {
+                    FixtureKind::Symlink
+                } else {
+                    FixtureKind::CopyFile
+                };
+                FixtureError::new(kind)
+                    .with_source_path(&job.source)
+                    .with_target_path(&job.target)
+            })?;
+        report.bytes += bytes;
+        report.files.push(job.rel.clone());
+    }
+    if options.require_match && report.files.is_empty() && report.skipped.is_empty() {
+        return Err(FixtureError::new(FixtureKind::Walk)
+            .with_source_path(&source)
+            .chain(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "pattern matched no files: {:?} in {}",
+                    includes,
+                    source.display()
+                ),
+            )));
+    }
+    // Children are visited after their parent, so apply directory metadata in reverse to keep
+    // later copies from bumping the mtime or being blocked by read-only permissions.
+    for (source_path, target_path) in dirs.iter().rev() {
+        if options.preserve_mtime {
+            copy_mtime(source_path, target_path, false).chain_with(|| {
+                FixtureError::new(FixtureKind::CopyFile)
+                    .with_source_path(source_path)
+                    .with_target_path(target_path)
+            })?;
+        }
+        if options.preserve_permissions {
+            let permissions = fs::metadata(source_path)
+                .chain_with(|| {
+                    FixtureError::new(FixtureKind::Metadata).with_source_path(source_path)
+                })?
+                .permissions();
+            fs::set_permissions(target_path, permissions).chain_with(|| {
+                FixtureError::new(FixtureKind::Permissions).with_target_path(target_path)
+            })?;
+        }
+    }
+    Ok(report)
+}
+
+struct CopyJob {
+    source: path::PathBuf,
+    target: path::PathBuf,
+    rel: path::PathBuf,
+    is_symlink: bool,
+    render: bool,
+}
+
+impl CopyJob {
+    /// Copy the entry, returning the number of content bytes.
+    fn run(&self, options: &CopyOptions) -> io::Result<u64> {
+        let mut bytes = 0;
+        let mut linked = false;
+        let rendered = match options.template {
+            Some(ref vars) if self.render => render_file(&self.source, &self.target, vars)?,
+            _ => None,
+        };
+        if let Some(len) = rendered {
+            bytes = len;
+        } else if self.is_symlink {
+            copy_symlink(&self.source, &self.target)?;
+        } else {
+            let (len, hardlinked) =
+                materialize_file(&self.source, &self.target, options.materialize)?;
+            bytes = len;
+            linked = hardlinked;
+        }
+        if options.preserve_mtime && !linked {
+            copy_mtime(&self.source, &self.target, self.is_symlink)?;
+        }
+        Ok(bytes)
+    }
+}
+
+/// Run `jobs`, in parallel if requested.
+///
+/// Results are in the same order as `jobs`.  After a failure, no new jobs are started, leaving
+/// their results as `None`; every job before the failure has still run, so the first error is the
+/// same as for a serial copy.
+fn run_jobs(jobs: &[CopyJob], options: &CopyOptions) -> Vec<Option<io::Result<u64>>> {
+    let threads = match options.threads {
+        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n,
+    };
+    let threads = cmp::min(threads, jobs.len());
+    if threads <= 1 {
+        let mut results = Vec::with_capacity(jobs.len());
+        for job in jobs {
+            let result = job.run(options);
+            let failed = result.is_err();
+            results.push(Some(result));
+            if failed {
+                break;
+            }
+        }
+        results.resize_with(jobs.len(), || None);
+        return results;
+    }
+
+    let next = atomic::AtomicUsize::new(0);
+    let failed = atomic::AtomicBool::new(false);
+    let results: Vec<_> = jobs.iter().map(|_| sync::Mutex::new(None)).collect();
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| {
+                while !failed.load(atomic::Ordering::SeqCst) {
+                    let i = next.fetch_add(1, atomic::Ordering::SeqCst);
+                    let job = match jobs.get(i) {
+                        Some(job) => job,
+                        None => break,
+                    };
+                    let result = job.run(options);
+                    if result.is_err() {
+                        failed.store(true, atomic::Ordering::SeqCst);
+                    }
+                    *results[i].lock().expect("no panics while holding the lock") = Some(result);
+                }
+            });
+        }
+    });
+    results
+        .into_iter()
+        .map(|r| r.into_inner().expect("no panics while holding the lock"))
+        .collect()
+}
+
+/// Write `source` to `target` as a template, returning the rendered size.
+///
+/// Returns `None` without writing anything when `source` is not UTF-8.
+fn render_file(
+    source: &path::Path,
+    target: &path::Path,
+    vars: &TemplateVars,
+) -> io::Result<Option<u64>> {
+    let content = match String::from_utf8(fs::read(source)?) {
+        Ok(content) => content,
+        Err(_) => return Ok(None),
+    };
+    let rendered = vars.render(&content, target)?;
+    remove_existing(target)?;
+    fs::write(target, &rendered)?;
+    fs::set_permissions(target, fs::metadata(source)?.permissions())?;
+    Ok(Some(rendered.len() as u64))
+}
+
+/// Create `target` with the content of `source`, returning the size and whether it was hard linked.
+fn materialize_file(
+    source: &path::Path,
+    target: &path::Path,
+    materialize: Materialize,
+) -> io::Result<(u64, bool)> {
+    remove_existing(target)?;
+    if materialize == Materialize::Copy {
+        return fs::copy(source, target).map(|bytes| (bytes, false));
+    }
+
+    let metadata = fs::metadata(source)?;
+    if reflink(source, target).is_ok() {
+        fs::set_permissions(target, metadata.permissions())?;
+        return Ok((metadata.len(), false));
+    }
+    if materialize == Materialize::ReflinkOrHardlink
+        && metadata.permissions().readonly()
+        && fs::hard_link(source, target).is_ok()
+    {
+        return Ok((metadata.len(), true));
+    }
+    fs::copy(source, target).map(|bytes| (bytes, false))
+}
+
+#[cfg(target_os = "linux")]
+fn reflink(source: &path::Path, target: &path::Path) -> io::Result<()> {
+    use std::os::unix::io::AsRawFd;
+
+    let source = fs::File::open(source)?;
+    let target_file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(target)?;
+    // SAFETY: both descriptors are open for the duration of the call and `FICLONE` does not
+    // retain them.
+    let ret = unsafe { libc::ioctl(target_file.as_raw_fd(), libc::FICLONE, source.as_raw_fd()) };
+    if ret == -1 {
+        let err = io::Error::last_os_error();
+        drop(target_file);
+        let _ = fs::remove_file(target);
+        return Err(err);
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn reflink(_source: &path::Path, _target: &path::Path) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Other,
+        "reflinks are not supported on this platform",
+    ))
+}
+
+/// Create `dir` within `target`, recording the directories that didn't exist yet.
+fn create_dirs(
+    target: &path::Path,
+    dir: &path::Path,
+    created: &mut Vec<path::PathBuf>,
+) -> Result<(), FixtureError> {
+    let mut missing = Vec::new();
+    let mut current = dir;
+    while current != target && fs::symlink_metadata(current).is_err() {
+        missing.push(current);
+        current = current.parent().expect("`dir` to be within `target`");
+    }
+    fs::create_dir_all(dir)
+        .chain_with(|| FixtureError::new(FixtureKind::CreateDir).with_target_path(dir))?;
+    for dir in missing.into_iter().rev() {
+        let rel = dir
+            .strip_prefix(target)
+            .expect("`dir` to be within `target`");
+        created.push(rel.to_owned());
+    }
     Ok(())
 }
+
+/// Glob `patterns` relative to `root`, in gitignore syntax.
+fn overrides<I>(
+    root: &path::Path,
+    patterns: I,
+) -> Result<ignore::overrides::Override, ignore::Error>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut builder = ignore::overrides::OverrideBuilder::new(root);
+    for pattern in patterns {
+        builder.add(&pattern)?;
+    }
+    builder.build()
+}
+
+/// A lone `*` would otherwise match entries in subdirectories too.
+fn normalize_pattern(pattern: &str) -> String {
+    if pattern == "*" {
+        "/*".to_owned()
+    } else {
+        pattern.to_owned()
+    }
+}
+
+fn copy_mtime(source: &path::Path, target: &path::Path, is_symlink: bool) -> io::Result<()> {
+    if is_symlink {
+        let metadata = fs::symlink_metadata(source)?;
+        let mtime = filetime::FileTime::from_last_modification_time(&metadata);
+        filetime::set_symlink_file_times(target, mtime, mtime)
+    } else {
+        let metadata = fs::metadata(source)?;
+        let mtime = filetime::FileTime::from_last_modification_time(&metadata);
+        filetime::set_file_mtime(target, mtime)
+    }
+}
+
+/// Remove whatever file or symlink is at `target` so writing to it can't follow a symlink.
+fn remove_existing(target: &path::Path) -> io::Result<()> {
+    match fs::symlink_metadata(target) {
+        Ok(metadata) if !metadata.is_dir() => fs::remove_file(target),
+        _ => Ok(()),
+    }
+}
+
+fn copy_symlink(source: &path::Path, target: &path::Path) -> io::Result<()> {
+    let link = fs::read_link(source)?;
+    remove_existing(target)?;
+    symlink(&link, target, source.is_dir())
+}
+
+#[cfg(unix)]
+pub(crate) fn symlink(link: &path::Path, target: &path::Path, _is_dir: bool) -> io::Result<()> {
+    std::os::unix::fs::symlink(link, target)
+}
+
+#[cfg(windows)]
+pub(crate) fn symlink(link: &path::Path, target: &path::Path, is_dir: bool) -> io::Result<()> {
+    if is_dir {
+        std::os::windows::fs::symlink_dir(link, target)
+    } else {
+        std::os::windows::fs::symlink_file(link, target)
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+pub(crate) fn symlink(_link: &path::Path, _target: &path::Path, _is_dir: bool) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Other,
+        "symlinks are not supported on this platform",
+    ))
+}
+
+fn file_digest(path: &path::Path, algorithm: digest::Algorithm) -> Result<String, FixtureError> {
+    digest::file_digest(algorithm, path)
+        .chain_with(|| FixtureError::new(FixtureKind::ReadFile).with_source_path(path))
+}
@@ -0,0 +1,191 @@
+use std::fs;
+use std::io;
+use std::path;
+
+use super::errors::*;
+use super::ChildPath;
+use super::TempDir;
+use crate::paths::is_enclosed;
+
+/// A tree of text files stored in a single text file.
+///
+/// The format is [txtar](https://pkg.go.dev/golang.org/x/tools/txtar): an optional comment
+/// followed by files, each introduced by a `-- path --` marker line.
+///
+/// ```text
+/// Anything before the first marker is a comment.
+/// -- hello.txt --
+/// Hello World
+/// -- sub/empty.txt --
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::fixture::Txtar;
+///
+/// let tree = Txtar::parse("-- hello.txt --\nHello World\n-- sub/empty.txt --\n");
+/// let files: Vec<_> = tree.files().map(|(path, _)| path.to_str().unwrap()).collect();
+/// assert_eq!(files, ["hello.txt", "sub/empty.txt"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Txtar {
+    comment: String,
+    files: Vec<(path::PathBuf, String)>,
+}
+
+impl Txtar {
+    /// Parse txtar-formatted `text`.
+    ///
+    /// Parsing never fails; text without markers is all comment.
+    pub fn parse(text: &str) -> Self {
+        let mut comment = String::new();
+        let mut files: Vec<(path::PathBuf, String)> = Vec::new();
+        for line in text.split_inclusive('\n') {
+            if let Some(name) = marker(line) {
+                files.push((path::PathBuf::from(name), String::new()));
+            } else if let Some((_, data)) = files.last_mut() {
+                data.push_str(line);
+            } else {
+                comment.push_str(line);
+            }
+        }
+        Self { comment, files }
+    }
+
+    /// Read and parse the txtar file at `path`.
+    pub fn from_file<P>(path: P) -> Result<Self, FixtureError>
+    where
+        P: AsRef<path::Path>,
+    {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .chain_with(|| FixtureError::new(FixtureKind::ReadFile).with_source_path(path))?;
+        Ok(Self::parse(&text))
+    }
+
+    /// Text before the first file.
+    pub fn comment(&self) -> &str {
+        &self.comment
+    }
+
+    /// Relative paths and content of each file, in the order they appear.
+    pub fn files(&self) -> impl Iterator<Item = (&path::Path, &str)> {
+        self.files.iter().map(|(p, d)| (p.as_path(), d.as_str()))
+    }
+
+    fn write_to(&self, root: &path::Path) -> Result<(), FixtureError> {
+        // Check every path up front so a bad entry doesn't leave a partially written tree.
+        if let Some((rel, _)) = self.files.iter().find(|(rel, _)| !is_enclosed(rel)) {
+            return Err(FixtureError::new(FixtureKind::WriteFile)
+                .with_target_path(root.join(rel))
+                .chain(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("txtar file escapes the fixture: {}", rel.display()),
+                )));
+        }
+        for (rel, data) in &self.files {
+            let path = root.join(rel);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).chain_with(|| {
+                    FixtureError::new(FixtureKind::CreateDir).with_target_path(parent)
+                })?;
+            }
+            fs::write(&path, data)
+                .chain_with(|| FixtureError::new(FixtureKind::WriteFile).with_target_path(&path))?;
+        }
+        Ok(())
+    }
+}
+
+impl<'s> From<&'s str> for Txtar {
+    fn from(text: &'s str) -> Self {
+        Self::parse(text)
+    }
+}
+
+impl From<String> for Txtar {
+    fn from(text: String) -> Self {
+        Self::parse(&text)
+    }
+}
+
+fn marker(line: &str) -> Option<&str> {
+    let line = line.trim_end_matches(['\n', '\r']);
+    let name = line.strip_prefix("-- ")?.strip_suffix(" --")?.trim();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+/// Write a tree of files described by a [`Txtar`] into [`TempDir`].
+///
+/// [`Txtar`]: struct.Txtar.html
+/// [`TempDir`]: struct.TempDir.html
+pub trait PathWriteTxtar {
+    /// Write each file in `tree` relative to the current path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// temp.write_txtar("-- hello.txt --\nHello World\n-- sub/empty.txt --\n")
+    ///     .unwrap();
+    /// temp.child("hello.txt").assert("Hello World\n");
+    /// temp.close().unwrap();
+    /// ```
+    fn write_txtar<T>(&self, tree: T) -> Result<(), FixtureError>
+    where
+        T: Into<Txtar>;
+
+    /// Write each file in the txtar file at `path` relative to the current path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// temp.write_txtar_file("tests/cases/basic.txtar").unwrap();
+    /// temp.close().unwrap();
+    /// ```
+    fn write_txtar_file<P>(&self, path: P) -> Result<(), FixtureError>
+    where
+        P: AsRef<path::Path>;
+}
+
+impl PathWriteTxtar for TempDir {
+    fn write_txtar<T>(&self, tree: T) -> Result<(), FixtureError>
+    where
+        T: Into<Txtar>,
+    {
+        tree.into().write_to(self.path())
+    }
+
+    fn write_txtar_file<P>(&self, path: P) -> Result<(), FixtureError>
+    where
+        P: AsRef<path::Path>,
+    {
+        Txtar::from_file(path)?.write_to(self.path())
+    }
+}
+
+impl PathWriteTxtar for ChildPath {
+    fn write_txtar<T>(&self, tree: T) -> Result<(), FixtureError>
+    where
+        T: Into<Txtar>,
+    {
+        tree.into().write_to(self.path())
+    }
+
+    fn write_txtar_file<P>(&self, path: P) -> Result<(), FixtureError>
+    where
+        P: AsRef<path::Path>,
+    {
+        Txtar::from_file(path)?.write_to(self.path())
+    }
+}
@@ -17,6 +17,7 @@
 //! - [`write_binary`][`FileWriteBin`] a [`ChildPath`] or [`NamedTempFile`]
 //! - [`write_str`][`FileWriteStr`] a [`ChildPath`] or [`NamedTempFile`]
 //! - [`write_file`][`FileWriteFile`] a [`ChildPath`] or [`NamedTempFile`]
+//! - [`write_template`][`FileWriteTemplate`] a [`ChildPath`] or [`NamedTempFile`]
 //! - [`copy_from`][`PathCopy`] a pristine folder to a [`ChildPath`] or [`TempDir`]
 //!
 //! Validating
@@ -49,6 +50,7 @@
 //! [`FileWriteBin`]: fixture/trait.FileWriteBin.html
 //! [`FileWriteStr`]: fixture/trait.FileWriteStr.html
 //! [`FileWriteFile`]: fixture/trait.FileWriteFile.html
+//! [`FileWriteTemplate`]: fixture/trait.FileWriteTemplate.html
 //! [`PathCopy`]: fixture/trait.PathCopy.html
 //! [`PathAssert`]: assert/trait.PathAssert.html
 //! [dir-diff]: https://crates.io/crates/dir-diff
@@ -58,6 +60,12 @@
 pub mod assert;
 pub mod fixture;
 
+#[cfg(any(feature = "tar", feature = "zip"))]
+mod archive;
+mod digest;
+mod paths;
+mod walk;
+
 // Pulling this in for convenience-sake
 #[doc(inline)]
 pub use crate::fixture::TempDir;
@@ -69,13 +77,25 @@ pub use crate::fixture::NamedTempFile;
 /// Extension traits that are useful to have available.
 pub mod prelude {
     pub use crate::assert::PathAssert;
+    pub use crate::fixture::FileAppend;
+    pub use crate::fixture::FileRead;
     pub use crate::fixture::FileTouch;
     pub use crate::fixture::FileWriteBin;
     pub use crate::fixture::FileWriteFile;
+    pub use crate::fixture::FileWriteLines;
     pub use crate::fixture::FileWriteStr;
+    pub use crate::fixture::FileWriteTemplate;
     pub use crate::fixture::PathChild;
     pub use crate::fixture::PathCopy;
     pub use crate::fixture::PathCreateDir;
+    pub use crate::fixture::PathDigest;
+    #[cfg(any(feature = "tar", feature = "zip"))]
+    pub use crate::fixture::PathExtract;
+    pub use crate::fixture::PathList;
+    pub use crate::fixture::PathRemove;
+    pub use crate::fixture::PathRename;
+    pub use crate::fixture::PathSnapshot;
+    pub use crate::fixture::PathWriteTxtar;
 }
 
 #[macro_use]
@@ -0,0 +1,23 @@
+//! Path checks shared by fixtures and assertions.
+
+use std::path;
+
+/// Whether `rel` stays within the directory it is relative to.
+///
+/// Absolute paths are rejected, as are `..` components that climb above the starting directory.
+/// `..` within the path, like `a/../b`, is allowed.  The check is lexical, so callers that write
+/// through existing directories must still guard against symlinks.
+pub(crate) fn is_enclosed(rel: &path::Path) -> bool {
+    let mut depth = 0_usize;
+    for component in rel.components() {
+        match component {
+            path::Component::Normal(_) => depth += 1,
+            path::Component::CurDir => {}
+            path::Component::ParentDir if depth > 0 => depth -= 1,
+            path::Component::ParentDir | path::Component::RootDir | path::Component::Prefix(_) => {
+                return false;
+            }
+        }
+    }
+    true
+}
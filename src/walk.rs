@@ -0,0 +1,33 @@
+//! Directory listings shared by fixtures and assertions.
+
+use std::path;
+
+use walkdir;
+
+/// Entries below `root`, paired with their path relative to `root`.
+///
+/// `root` itself is not included.  Entries come in walk order.
+pub(crate) fn entries(
+    root: &path::Path,
+) -> impl Iterator<Item = Result<(path::PathBuf, walkdir::DirEntry), walkdir::Error>> {
+    let root = root.to_owned();
+    walkdir::WalkDir::new(&root)
+        .min_depth(1)
+        .into_iter()
+        .map(move |entry| {
+            let entry = entry?;
+            let rel = entry
+                .path()
+                .strip_prefix(&root)
+                .expect("entries to be under `root`")
+                .to_owned();
+            Ok((rel, entry))
+        })
+}
+
+/// Drop `.` components so `./bin/tool` and `bin/` compare equal to `bin/tool` and `bin`.
+pub(crate) fn normalize(path: &path::Path) -> path::PathBuf {
+    path.components()
+        .filter(|c| *c != path::Component::CurDir)
+        .collect()
+}
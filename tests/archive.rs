@@ -0,0 +1,54 @@
+#![cfg(any(feature = "tar", feature = "zip"))]
+
+use assert_fs::assert::archive;
+use assert_fs::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+#[cfg(feature = "tar")]
+fn tar_contents() {
+    let source = assert_fs::TempDir::new().unwrap();
+    source.child("bin/tool").write_str("#!/bin/sh\n").unwrap();
+    source.child("README.md").write_str("# Tool\n").unwrap();
+
+    let package = assert_fs::NamedTempFile::new("package.tar").unwrap();
+    let mut builder = tar::Builder::new(std::fs::File::create(package.path()).unwrap());
+    builder.append_dir_all(".", source.path()).unwrap();
+    builder.into_inner().unwrap();
+
+    package.assert(archive::contains_entry("bin/tool").with_content("#!/bin/sh\n"));
+    package.assert(archive::contains_entry("bin/missing").not());
+    package.assert(archive::contains_entry("./bin/tool"));
+    package.assert(
+        archive::contains_entry("README.md")
+            .with_content("wrong")
+            .not(),
+    );
+    package.assert(archive::entries_exactly(["bin/tool", "README.md"]));
+    package.assert(archive::entries_exactly(["./bin/tool", "./README.md"]));
+    package.assert(archive::entries_exactly(["bin/tool"]).not());
+    package.assert(archive::matches_dir(source.path()));
+
+    source.child("extra.txt").touch().unwrap();
+    package.assert(archive::matches_dir(source.path()).not());
+
+    package.close().unwrap();
+    source.close().unwrap();
+}
+
+#[test]
+#[cfg(feature = "zip")]
+fn zip_contents() {
+    let package = assert_fs::NamedTempFile::new("package.zip").unwrap();
+    let mut writer = zip::ZipWriter::new(std::fs::File::create(package.path()).unwrap());
+    let options = zip::write::FileOptions::default().unix_permissions(0o755);
+    writer.start_file("bin/tool", options).unwrap();
+    std::io::Write::write_all(&mut writer, b"#!/bin/sh\n").unwrap();
+    writer.finish().unwrap();
+
+    package.assert(archive::contains_entry("bin/tool").with_mode(0o755));
+    package.assert(archive::contains_entry("bin/tool").with_mode(0o644).not());
+    package.assert(archive::entries_exactly(["bin/tool"]));
+
+    package.close().unwrap();
+}
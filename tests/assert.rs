@@ -23,3 +23,44 @@ fn verify_failure_output() {
     let f = assert_fs::fixture::ChildPath::new("Cargo.toml");
     f.assert("Not real content");
 }
+
+#[test]
+fn assert_eventually_waits() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let input_file = temp.child("foo.txt");
+
+    let path = input_file.path().to_owned();
+    let writer = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        std::fs::write(path, "done").unwrap();
+    });
+
+    input_file.assert_eventually("done", std::time::Duration::from_secs(10));
+
+    writer.join().unwrap();
+    temp.close().unwrap();
+}
+
+#[test]
+fn assert_eventually_without_deadline() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let input_file = temp.child("foo.txt");
+
+    let path = input_file.path().to_owned();
+    let writer = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        std::fs::write(path, "done").unwrap();
+    });
+
+    input_file.assert_eventually("done", std::time::Duration::MAX);
+
+    writer.join().unwrap();
+    temp.close().unwrap();
+}
+
+#[test]
+#[should_panic]
+fn assert_eventually_times_out() {
+    let f = assert_fs::fixture::ChildPath::new("Cargo.toml");
+    f.assert_eventually("Not real content", std::time::Duration::from_millis(50));
+}
@@ -0,0 +1,45 @@
+use std::env;
+use std::thread;
+
+use assert_fs::prelude::*;
+
+// Everything that changes the current directory is in one test, so no other test in this binary
+// can move it between a guard being dropped and the restored directory being checked.
+#[test]
+fn chdir() {
+    let temp = assert_fs::TempDir::new().unwrap();
+
+    let original = {
+        let guard = temp.chdir().unwrap();
+        assert_eq!(
+            env::current_dir().unwrap().canonicalize().unwrap(),
+            temp.path().canonicalize().unwrap()
+        );
+        std::fs::write("foo.txt", "Hello").unwrap();
+        guard.previous().to_owned()
+    };
+
+    assert_eq!(env::current_dir().unwrap(), original);
+    temp.child("foo.txt").assert("Hello");
+    temp.close().unwrap();
+
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            thread::spawn(move || {
+                let temp = assert_fs::TempDir::new().unwrap();
+                for _ in 0..10 {
+                    let _cwd = temp.chdir().unwrap();
+                    std::fs::write("id.txt", i.to_string()).unwrap();
+                    thread::yield_now();
+                    assert_eq!(std::fs::read_to_string("id.txt").unwrap(), i.to_string());
+                }
+                temp.close().unwrap();
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(env::current_dir().unwrap(), original);
+}
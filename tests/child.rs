@@ -0,0 +1,111 @@
+use std::ffi::OsStr;
+use std::path::Path;
+
+use assert_fs::prelude::*;
+
+fn takes_path<P: AsRef<Path>>(path: P) -> String {
+    path.as_ref().display().to_string()
+}
+
+fn takes_os_str<S: AsRef<OsStr>>(s: S) -> String {
+    s.as_ref().to_string_lossy().into_owned()
+}
+
+#[test]
+fn fixtures_as_paths() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let child = temp.child("foo.txt");
+    let file = assert_fs::NamedTempFile::new("bar.txt").unwrap();
+
+    assert_eq!(takes_path(&temp), temp.path().display().to_string());
+    assert_eq!(takes_path(&child), child.path().display().to_string());
+    assert_eq!(takes_path(&file), file.path().display().to_string());
+    assert_eq!(takes_os_str(&child), child.path().display().to_string());
+    assert_eq!(child.to_string(), child.path().display().to_string());
+    assert_eq!(child.file_name(), Some(OsStr::new("foo.txt")));
+    assert!(temp.is_dir());
+    assert!(format!("{:?}", child).contains("foo.txt"));
+
+    assert_eq!(
+        file.sibling("baz.txt").path(),
+        file.parent().unwrap().join("baz.txt")
+    );
+
+    file.close().unwrap();
+    temp.close().unwrap();
+}
+
+#[test]
+fn parent() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    assert!(temp.parent().is_none());
+
+    let out = temp.child("out/report.txt").parent().unwrap();
+    assert_eq!(out.relative_path(), Path::new("out"));
+    let root = out.parent().unwrap();
+    assert_eq!(root.path(), temp.path());
+    assert!(root.parent().is_none());
+
+    let file = assert_fs::NamedTempFile::new("foo.txt").unwrap();
+    let dir = file.parent().unwrap();
+    assert_eq!(Some(dir.path()), file.path().parent());
+    assert!(dir.parent().is_none());
+
+    let plain = assert_fs::fixture::ChildPath::new("a/b");
+    assert_eq!(plain.parent().unwrap().path(), Path::new("a"));
+
+    file.close().unwrap();
+    temp.close().unwrap();
+}
+
+#[test]
+fn relative_path() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let report = temp.child("out").child("report.txt");
+    assert_eq!(report.relative_path(), Path::new("out/report.txt"));
+    assert_eq!(
+        report.sibling("summary.txt").relative_path(),
+        Path::new("out/summary.txt")
+    );
+    assert_eq!(report.parent().unwrap().relative_path(), Path::new("out"));
+
+    report.write_str("done").unwrap();
+    let moved = report.rename_to("final.txt").unwrap();
+    assert_eq!(moved.relative_path(), Path::new("out/final.txt"));
+
+    let outside = assert_fs::fixture::ChildPath::new("/some/where");
+    assert_eq!(outside.relative_path(), Path::new("/some/where"));
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn named_temp_file_child() {
+    let file = assert_fs::NamedTempFile::new("foo.txt").unwrap();
+    let child = file.child("bar.txt");
+    assert_eq!(child.path().parent(), file.path().parent());
+    assert_eq!(child.relative_path(), Path::new("bar.txt"));
+    assert_eq!(file.parent().unwrap().path(), file.path().parent().unwrap());
+
+    child.touch().unwrap();
+    child.assert("");
+
+    file.close().unwrap();
+}
+
+#[test]
+fn try_child_rejects_escaping_paths() {
+    let temp = assert_fs::TempDir::new().unwrap();
+
+    let child = temp.try_child("sub/../foo.txt").unwrap();
+    assert_eq!(child.path(), temp.path().join("sub/../foo.txt"));
+    temp.child("sub").try_child("nested/./bar.txt").unwrap();
+
+    for path in ["..", "sub/../../foo.txt", "/etc/passwd"] {
+        let err = temp.try_child(path).unwrap_err();
+        assert_eq!(err.kind(), assert_fs::fixture::FixtureKind::InvalidPath);
+        assert!(err.to_string().contains("escapes"), "{}", err);
+    }
+
+    temp.close().unwrap();
+}
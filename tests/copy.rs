@@ -0,0 +1,443 @@
+use assert_fs::fixture::CopyOptions;
+use assert_fs::fixture::Overwrite;
+use assert_fs::fixture::TemplateVars;
+use assert_fs::prelude::*;
+
+#[test]
+fn copy_from_with_excludes() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let options = CopyOptions::new().exclude("*.txt");
+    temp.copy_from_with("tests/fixture", &["**"], &options)
+        .unwrap();
+
+    temp.assert(assert_fs::assert::dir_contains_exactly([".gitattributes"]));
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn copy_from_with_excluded_dir() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let options = CopyOptions::new().exclude("fixture");
+    temp.copy_from_with("tests", &["**"], &options).unwrap();
+
+    temp.assert(assert_fs::assert::dir_lacks(["fixture", "fixture/**"]));
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(unix)]
+fn copy_from_with_excluded_dir_not_walked() {
+    let source = assert_fs::TempDir::new().unwrap();
+    source.child("a.txt").touch().unwrap();
+    source.child("target").create_dir_all().unwrap();
+    // Walking into `target` while following links fails on the loop, so this only passes if
+    // neither the copy nor the ignore file handling walks an excluded directory.
+    std::os::unix::fs::symlink(".", source.child("target/loop").path()).unwrap();
+
+    let options = CopyOptions::new().follow_links(true).gitignore(true);
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.copy_from_with(source.path(), &["**"], &options)
+        .unwrap_err();
+    temp.close().unwrap();
+
+    let options = options.exclude("target");
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.copy_from_with(source.path(), &["**"], &options)
+        .unwrap();
+    temp.assert(assert_fs::assert::dir_contains_exactly(["a.txt"]));
+
+    temp.close().unwrap();
+    source.close().unwrap();
+}
+
+#[test]
+fn copy_from_with_no_hidden() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let options = CopyOptions::new().hidden(false);
+    temp.copy_from_with("tests/fixture", &["**"], &options)
+        .unwrap();
+
+    temp.assert(assert_fs::assert::dir_contains_exactly(["hello.txt"]));
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn copy_from_with_overwrite() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("hello.txt").write_str("existing").unwrap();
+
+    let options = CopyOptions::new().overwrite(Overwrite::Skip);
+    temp.copy_from_with("tests/fixture", &["*.txt"], &options)
+        .unwrap();
+    temp.child("hello.txt").assert("existing");
+
+    let options = CopyOptions::new().overwrite(Overwrite::Error);
+    let err = temp
+        .copy_from_with("tests/fixture", &["*.txt"], &options)
+        .unwrap_err();
+    assert_eq!(err.kind(), assert_fs::fixture::FixtureKind::CopyFile);
+
+    temp.copy_from("tests/fixture", &["*.txt"]).unwrap();
+    temp.child("hello.txt").assert("hello\n");
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(unix)]
+fn copy_from_with_overwrite_symlink() {
+    let source = assert_fs::TempDir::new().unwrap();
+    source.child("a.txt").write_str("copied").unwrap();
+    let outside = assert_fs::TempDir::new().unwrap();
+    let victim = outside.child("victim.txt");
+    victim.write_str("victim").unwrap();
+
+    for options in [
+        CopyOptions::new(),
+        CopyOptions::new().template(TemplateVars::new()),
+    ] {
+        let temp = assert_fs::TempDir::new().unwrap();
+        std::os::unix::fs::symlink(victim.path(), temp.child("a.txt").path()).unwrap();
+
+        temp.copy_from_with(source.path(), &["*.txt"], &options)
+            .unwrap();
+
+        temp.child("a.txt").assert("copied");
+        assert!(!std::fs::symlink_metadata(temp.child("a.txt").path())
+            .unwrap()
+            .file_type()
+            .is_symlink());
+        victim.assert("victim");
+
+        temp.close().unwrap();
+    }
+
+    outside.close().unwrap();
+    source.close().unwrap();
+}
+
+#[test]
+#[cfg(unix)]
+fn copy_from_with_faithful() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let source = assert_fs::TempDir::new().unwrap();
+    let script = source.child("bin/run.sh");
+    script.write_str("#!/bin/sh\n").unwrap();
+    std::fs::set_permissions(script.path(), std::fs::Permissions::from_mode(0o755)).unwrap();
+    filetime::set_file_mtime(
+        script.path(),
+        filetime::FileTime::from_unix_time(1_000_000, 0),
+    )
+    .unwrap();
+    std::os::unix::fs::symlink("bin/run.sh", source.child("run").path()).unwrap();
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.copy_from_with(source.path(), &["**"], &CopyOptions::faithful())
+        .unwrap();
+
+    let link = temp.child("run");
+    assert_eq!(
+        std::fs::read_link(link.path()).unwrap(),
+        std::path::Path::new("bin/run.sh")
+    );
+    let metadata = std::fs::metadata(temp.child("bin/run.sh").path()).unwrap();
+    assert_eq!(metadata.permissions().mode() & 0o777, 0o755);
+    assert_eq!(
+        filetime::FileTime::from_last_modification_time(&metadata),
+        filetime::FileTime::from_unix_time(1_000_000, 0)
+    );
+
+    temp.close().unwrap();
+    source.close().unwrap();
+}
+
+#[test]
+#[cfg(unix)]
+fn copy_from_with_special_files() {
+    let source = assert_fs::TempDir::new().unwrap();
+    source.child("a.txt").touch().unwrap();
+    let _socket = std::os::unix::net::UnixListener::bind(source.child("sock").path()).unwrap();
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    let report = temp
+        .copy_from_with(source.path(), &["**"], &CopyOptions::new())
+        .unwrap();
+    temp.assert(assert_fs::assert::dir_contains_exactly(["a.txt"]));
+    assert_eq!(
+        report.skipped().collect::<Vec<_>>(),
+        [std::path::Path::new("sock")]
+    );
+
+    let err = temp
+        .copy_from_with(source.path(), &["**"], &CopyOptions::faithful())
+        .unwrap_err();
+    assert_eq!(err.kind(), assert_fs::fixture::FixtureKind::CopyFile);
+
+    temp.close().unwrap();
+    source.close().unwrap();
+}
+
+#[test]
+fn copy_from_with_gitignore() {
+    let source = assert_fs::TempDir::new().unwrap();
+    source
+        .child(".gitignore")
+        .write_str("*.log\n!keep.log\nbuild/\n")
+        .unwrap();
+    source.child("a.log").touch().unwrap();
+    source.child("keep.log").touch().unwrap();
+    source.child("build/out/app").touch().unwrap();
+    source.child("sub/.gitignore").write_str("*.tmp\n").unwrap();
+    source.child("sub/a.tmp").touch().unwrap();
+    source.child("sub/a.txt").touch().unwrap();
+    source.child("b.tmp").touch().unwrap();
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    let options = CopyOptions::new().gitignore(true);
+    temp.copy_from_with(source.path(), &["**"], &options)
+        .unwrap();
+
+    temp.assert(assert_fs::assert::dir_contains_exactly([
+        ".gitignore",
+        "b.tmp",
+        "keep.log",
+        "sub",
+        "sub/.gitignore",
+        "sub/a.txt",
+    ]));
+
+    temp.close().unwrap();
+    source.close().unwrap();
+}
+
+#[test]
+fn copy_from_with_require_match() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let options = CopyOptions::new().require_match(true);
+
+    let report = temp
+        .copy_from_with("tests/fixture", &["*.txt"], &options)
+        .unwrap();
+    assert_eq!(report.files().count(), 1);
+
+    let err = temp
+        .copy_from_with("tests/fixture", &["*.md"], &options)
+        .unwrap_err();
+    assert_eq!(err.kind(), assert_fs::fixture::FixtureKind::Walk);
+    assert!(err.to_string().contains("pattern matched no files"));
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn copy_from_with_report() {
+    use std::path::Path;
+
+    let source = assert_fs::TempDir::new().unwrap();
+    source.child("src/lib.rs").touch().unwrap();
+    source.child("src/bin/main.rs").touch().unwrap();
+    source.child("docs/README.md").touch().unwrap();
+    source.child("build.rs").touch().unwrap();
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("build.rs").write_str("existing").unwrap();
+    temp.child("docs").create_dir_all().unwrap();
+
+    let options = CopyOptions::new().overwrite(Overwrite::Skip);
+    let report = temp
+        .copy_from_with(source.path(), &["**/*.rs", "docs"], &options)
+        .unwrap();
+
+    let mut files: Vec<_> = report.files().collect();
+    files.sort();
+    assert_eq!(
+        files,
+        [Path::new("src/bin/main.rs"), Path::new("src/lib.rs")]
+    );
+    let mut dirs: Vec<_> = report.dirs().collect();
+    dirs.sort();
+    assert_eq!(dirs, [Path::new("src"), Path::new("src/bin")]);
+    assert_eq!(
+        report.skipped().collect::<Vec<_>>(),
+        [Path::new("build.rs")]
+    );
+    temp.child("build.rs").assert("existing");
+
+    temp.close().unwrap();
+    source.close().unwrap();
+}
+
+#[test]
+#[cfg(unix)]
+fn copy_from_with_materialize() {
+    use assert_fs::fixture::Materialize;
+    use std::os::unix::fs::MetadataExt;
+
+    let source = assert_fs::TempDir::new().unwrap();
+    source.child("writable.txt").write_str("writable").unwrap();
+    let readonly = source.child("readonly.txt");
+    readonly.write_str("readonly").unwrap();
+    let mut permissions = std::fs::metadata(readonly.path()).unwrap().permissions();
+    permissions.set_readonly(true);
+    std::fs::set_permissions(readonly.path(), permissions).unwrap();
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    let options = CopyOptions::new().materialize(Materialize::ReflinkOrHardlink);
+    let report = temp
+        .copy_from_with(source.path(), &["*.txt"], &options)
+        .unwrap();
+    assert_eq!(report.bytes(), 16);
+
+    temp.child("writable.txt").assert("writable");
+    temp.child("readonly.txt").assert("readonly");
+    let writable = std::fs::metadata(temp.child("writable.txt").path()).unwrap();
+    assert_eq!(writable.nlink(), 1);
+    // The read-only file is reflinked where the filesystem supports it, like btrfs or XFS, and
+    // hard linked otherwise.
+    let copied = std::fs::metadata(temp.child("readonly.txt").path()).unwrap();
+    let original = std::fs::metadata(readonly.path()).unwrap();
+    if copied.ino() == original.ino() {
+        assert_eq!(copied.nlink(), 2);
+    } else {
+        assert_eq!(copied.nlink(), 1);
+        assert_eq!(original.nlink(), 1);
+    }
+
+    temp.close().unwrap();
+    source.close().unwrap();
+}
+
+#[test]
+fn copy_from_with_threads() {
+    let source = assert_fs::TempDir::new().unwrap();
+    for i in 0..50 {
+        source
+            .child(format!("dir{}/file{}.txt", i % 5, i))
+            .write_str(&i.to_string())
+            .unwrap();
+    }
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    let options = CopyOptions::new().threads(4);
+    let report = temp
+        .copy_from_with(source.path(), &["**"], &options)
+        .unwrap();
+    assert_eq!(report.files().count(), 50);
+    temp.child("dir3/file13.txt").assert("13");
+
+    temp.close().unwrap();
+    source.close().unwrap();
+}
+
+#[test]
+fn copy_from_with_threads_first_error() {
+    let source = assert_fs::TempDir::new().unwrap();
+    for i in 0..40 {
+        source
+            .child(format!("file{}.txt", i))
+            .write_str(&i.to_string())
+            .unwrap();
+    }
+
+    let copy = |threads| {
+        let temp = assert_fs::TempDir::new().unwrap();
+        // A directory in the way of a file makes copying that file fail.
+        for i in (3..40).step_by(4) {
+            temp.child(format!("file{}.txt", i))
+                .create_dir_all()
+                .unwrap();
+        }
+        let options = CopyOptions::new().threads(threads);
+        let err = temp
+            .copy_from_with(source.path(), &["*.txt"], &options)
+            .unwrap_err();
+        temp.close().unwrap();
+        err.source_path().unwrap().to_owned()
+    };
+
+    let expected = copy(1);
+    for _ in 0..20 {
+        assert_eq!(copy(8), expected);
+    }
+
+    source.close().unwrap();
+}
+
+#[test]
+fn copy_from_with_template() {
+    let source = assert_fs::TempDir::new().unwrap();
+    source
+        .child("config.toml")
+        .write_str("data = \"{{root}}/data\"\nname = \"{{ name }}\"\nport = {{port}}\n")
+        .unwrap();
+    source.child("blob.bin").write_binary(b"{{\xff").unwrap();
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    let options = CopyOptions::new().template(TemplateVars::new().var("port", "8080"));
+    temp.copy_from_with(source.path(), &["**"], &options)
+        .unwrap();
+
+    temp.child("config.toml").assert(format!(
+        "data = \"{}/data\"\nname = \"config.toml\"\nport = 8080\n",
+        temp.path().display()
+    ));
+    temp.child("blob.bin").assert(b"{{\xff" as &[u8]);
+
+    temp.close().unwrap();
+    source.close().unwrap();
+}
+
+#[test]
+fn copy_from_with_template_unknown_var() {
+    let source = assert_fs::TempDir::new().unwrap();
+    source
+        .child("config.toml")
+        .write_str("{{missing}}")
+        .unwrap();
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    let options = CopyOptions::new().template(TemplateVars::new());
+    let err = temp
+        .copy_from_with(source.path(), &["**"], &options)
+        .unwrap_err();
+    assert!(err.to_string().contains("missing"), "{}", err);
+
+    temp.close().unwrap();
+    source.close().unwrap();
+}
+
+#[test]
+fn copy_from_with_template_files() {
+    let source = assert_fs::TempDir::new().unwrap();
+    source
+        .child("config.toml")
+        .write_str("port = {{port}}\n")
+        .unwrap();
+    source
+        .child("src/lib.rs")
+        .write_str("fn f() -> String { format!(\"{{}}\", 1) }\n")
+        .unwrap();
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    let options = CopyOptions::new().template(TemplateVars::new().var("port", "8080"));
+    let err = temp
+        .copy_from_with(source.path(), &["**"], &options)
+        .unwrap_err();
+    assert!(err.to_string().contains("empty"), "{}", err);
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    let options = options.template_files("*.toml");
+    temp.copy_from_with(source.path(), &["**"], &options)
+        .unwrap();
+
+    temp.child("config.toml").assert("port = 8080\n");
+    temp.child("src/lib.rs")
+        .assert("fn f() -> String { format!(\"{{}}\", 1) }\n");
+
+    temp.close().unwrap();
+    source.close().unwrap();
+}
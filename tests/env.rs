@@ -0,0 +1,44 @@
+use std::env;
+
+use assert_fs::fixture::EnvSandbox;
+
+#[test]
+fn sandbox_creates_dirs() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let sandbox = EnvSandbox::new(&temp).unwrap();
+
+    let vars: Vec<_> = sandbox.vars().map(|(var, _)| var).collect();
+    assert_eq!(
+        vars,
+        [
+            "HOME",
+            "XDG_CONFIG_HOME",
+            "XDG_CACHE_HOME",
+            "XDG_DATA_HOME",
+            "TMPDIR"
+        ]
+    );
+    for (_, dir) in sandbox.vars() {
+        assert!(dir.is_dir(), "{}", dir.display());
+        assert!(dir.starts_with(temp.path()));
+    }
+    assert_eq!(sandbox.config(), temp.path().join("config"));
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn apply_restores_previous_values() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let sandbox = EnvSandbox::new(&temp).unwrap();
+    let home = env::var_os("HOME");
+
+    {
+        let _env = sandbox.apply();
+        assert_eq!(env::var_os("HOME").unwrap(), sandbox.home());
+        assert_eq!(env::var_os("XDG_DATA_HOME").unwrap(), sandbox.data());
+    }
+
+    assert_eq!(env::var_os("HOME"), home);
+    temp.close().unwrap();
+}
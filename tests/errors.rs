@@ -0,0 +1,76 @@
+use std::error::Error;
+
+use assert_fs::fixture::FixtureKind;
+use assert_fs::prelude::*;
+
+#[test]
+fn error_includes_target_path() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let file = temp.child("file");
+    file.touch().unwrap();
+
+    let child = file.child("nested.txt");
+    let err = child.write_str("content").unwrap_err();
+
+    assert_eq!(err.kind(), FixtureKind::CreateDir);
+    assert_eq!(err.target_path(), Some(file.path()));
+    assert_eq!(err.source_path(), None);
+    assert!(err.source().is_some());
+    assert!(
+        err.to_string().contains(&file.path().display().to_string()),
+        "{}",
+        err
+    );
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn error_includes_source_path() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let missing = temp.child("missing.txt");
+
+    let err = temp
+        .child("copy.txt")
+        .write_file(missing.path())
+        .unwrap_err();
+
+    assert_eq!(err.kind(), FixtureKind::CopyFile);
+    assert_eq!(err.source_path(), Some(missing.path()));
+    assert_eq!(err.target_path(), Some(temp.child("copy.txt").path()));
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn error_is_not_found() {
+    let temp = assert_fs::TempDir::new().unwrap();
+
+    let err = temp.child("missing.txt").read_to_string().unwrap_err();
+    assert!(err.is_not_found());
+    assert!(!err.is_permission_denied());
+
+    let err = temp.child("missing").list().unwrap_err();
+    assert_eq!(err.kind(), FixtureKind::Walk);
+    assert!(err.is_not_found());
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn remove_and_rename_errors() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let missing = temp.child("missing.txt");
+
+    let err = missing.remove().unwrap_err();
+    assert_eq!(err.kind(), FixtureKind::Remove);
+    assert_eq!(err.target_path(), Some(missing.path()));
+    assert!(err.is_not_found());
+
+    let err = missing.rename_to("other.txt").unwrap_err();
+    assert_eq!(err.kind(), FixtureKind::Rename);
+    assert_eq!(err.source_path(), Some(missing.path()));
+    assert_eq!(err.target_path(), Some(temp.child("other.txt").path()));
+
+    temp.close().unwrap();
+}
@@ -0,0 +1,115 @@
+#![cfg(any(feature = "tar", feature = "zip"))]
+
+use assert_fs::fixture::FixtureKind;
+use assert_fs::prelude::*;
+
+#[test]
+#[cfg(feature = "tar")]
+fn extract_tar_gz() {
+    let archive = assert_fs::NamedTempFile::new("fixture.tar.gz").unwrap();
+    let file = std::fs::File::create(archive.path()).unwrap();
+    let encoder = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+    let mut builder = tar::Builder::new(encoder);
+    let mut header = tar::Header::new_gnu();
+    header.set_size(6);
+    header.set_mode(0o755);
+    builder
+        .append_data(&mut header, "bin/run.sh", &b"hello\n"[..])
+        .unwrap();
+    let mut header = tar::Header::new_gnu();
+    header.set_entry_type(tar::EntryType::Symlink);
+    header.set_size(0);
+    builder
+        .append_link(&mut header, "run", "bin/run.sh")
+        .unwrap();
+    builder.into_inner().unwrap().finish().unwrap();
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.extract_from(archive.path()).unwrap();
+
+    temp.child("bin/run.sh").assert("hello\n");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let metadata = std::fs::metadata(temp.child("bin/run.sh").path()).unwrap();
+        assert_eq!(metadata.permissions().mode() & 0o777, 0o755);
+        assert_eq!(
+            std::fs::read_link(temp.child("run").path()).unwrap(),
+            std::path::Path::new("bin/run.sh")
+        );
+    }
+
+    temp.close().unwrap();
+    archive.close().unwrap();
+}
+
+#[test]
+#[cfg(feature = "tar")]
+fn extract_tar_rejects_traversal() {
+    let archive = assert_fs::NamedTempFile::new("fixture.tar").unwrap();
+    let file = std::fs::File::create(archive.path()).unwrap();
+    let mut builder = tar::Builder::new(file);
+    let mut header = tar::Header::new_gnu();
+    let name = b"../evil.txt";
+    header.as_gnu_mut().unwrap().name[..name.len()].copy_from_slice(name);
+    header.set_size(4);
+    header.set_cksum();
+    builder.append(&header, &b"evil"[..]).unwrap();
+    builder.into_inner().unwrap();
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    let target = temp.child("target");
+    let err = target.extract_from(archive.path()).unwrap_err();
+    assert_eq!(err.kind(), FixtureKind::Extract);
+    temp.child("evil.txt").assert(predicates::path::missing());
+
+    temp.close().unwrap();
+    archive.close().unwrap();
+}
+
+#[test]
+#[cfg(feature = "zip")]
+fn extract_zip() {
+    let archive = assert_fs::NamedTempFile::new("fixture.zip").unwrap();
+    let file = std::fs::File::create(archive.path()).unwrap();
+    let mut writer = zip::ZipWriter::new(file);
+    let modified = zip::DateTime::from_date_and_time(2001, 9, 9, 1, 46, 40).unwrap();
+    let options = zip::write::FileOptions::default().last_modified_time(modified);
+    writer.start_file("sub/hello.txt", options).unwrap();
+    std::io::Write::write_all(&mut writer, b"hello\n").unwrap();
+    writer.finish().unwrap();
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.extract_from(archive.path()).unwrap();
+    temp.child("sub/hello.txt").assert("hello\n");
+    let metadata = std::fs::metadata(temp.child("sub/hello.txt").path()).unwrap();
+    assert_eq!(
+        filetime::FileTime::from_last_modification_time(&metadata),
+        filetime::FileTime::from_unix_time(1_000_000_000, 0)
+    );
+
+    temp.close().unwrap();
+    archive.close().unwrap();
+}
+
+#[test]
+#[cfg(feature = "zip")]
+fn extract_zip_rejects_traversal() {
+    let archive = assert_fs::NamedTempFile::new("fixture.zip").unwrap();
+    let file = std::fs::File::create(archive.path()).unwrap();
+    let mut writer = zip::ZipWriter::new(file);
+    writer
+        .start_file("../evil.txt", zip::write::FileOptions::default())
+        .unwrap();
+    std::io::Write::write_all(&mut writer, b"evil").unwrap();
+    writer.finish().unwrap();
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    let target = temp.child("target");
+    let err = target.extract_from(archive.path()).unwrap_err();
+    assert_eq!(err.kind(), FixtureKind::Extract);
+    temp.child("evil.txt").assert(predicates::path::missing());
+
+    temp.close().unwrap();
+    archive.close().unwrap();
+}
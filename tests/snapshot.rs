@@ -0,0 +1,102 @@
+use assert_fs::prelude::*;
+
+#[test]
+fn snapshot_no_change() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("a.txt").write_str("a").unwrap();
+    let before = temp.snapshot().unwrap();
+
+    let changes = before.diff(&temp.snapshot().unwrap());
+    assert!(changes.is_empty());
+    changes.assert_unchanged();
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn snapshot_create() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let before = temp.snapshot().unwrap();
+
+    temp.child("out/a.txt").touch().unwrap();
+
+    let changes = before.diff(&temp.snapshot().unwrap());
+    let created: Vec<_> = changes.created().collect();
+    assert_eq!(
+        created,
+        [
+            std::path::Path::new("out"),
+            std::path::Path::new("out/a.txt")
+        ]
+    );
+    changes.assert_only_created(["out", "out/a.txt"]);
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn snapshot_delete() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("a.txt").touch().unwrap();
+    temp.child("b.txt").touch().unwrap();
+    let before = temp.snapshot().unwrap();
+
+    std::fs::remove_file(temp.child("a.txt").path()).unwrap();
+
+    let changes = before.diff(&temp.snapshot().unwrap());
+    assert_eq!(changes.deleted().count(), 1);
+    changes.assert_only_deleted(["a.txt"]);
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn snapshot_modify() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("a.txt").write_str("a").unwrap();
+    temp.child("b.txt").write_str("b").unwrap();
+    let before = temp.snapshot().unwrap();
+
+    // Same size, so only the content tells the files apart.
+    temp.child("a.txt").write_str("z").unwrap();
+
+    let changes = before.diff(&temp.snapshot().unwrap());
+    changes.assert_only_modified(["a.txt"]);
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn snapshot_failure_message() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let before = temp.snapshot().unwrap();
+
+    temp.child("new.txt").touch().unwrap();
+
+    let changes = before.diff(&temp.snapshot().unwrap());
+    let err = std::panic::catch_unwind(|| {
+        changes.assert_unchanged();
+    })
+    .unwrap_err();
+    let message = err.downcast_ref::<String>().unwrap();
+    assert_eq!(
+        message,
+        "Unexpected changes\nexpected:\n  (none)\nactual:\n  created: new.txt\n"
+    );
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[should_panic]
+fn snapshot_wrong_change() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("a.txt").write_str("a").unwrap();
+    let before = temp.snapshot().unwrap();
+
+    std::fs::remove_file(temp.child("a.txt").path()).unwrap();
+
+    before
+        .diff(&temp.snapshot().unwrap())
+        .assert_only_modified(["a.txt"]);
+}
@@ -0,0 +1,56 @@
+use assert_fs::fixture::ChildPath;
+use assert_fs::fixture::TemplateVars;
+use assert_fs::prelude::*;
+
+#[test]
+fn write_template_root() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let config = temp.child("etc/config.toml");
+    config
+        .write_template("data = \"{{root}}/data\"\n", &TemplateVars::new())
+        .unwrap();
+    config.assert(format!("data = \"{}/data\"\n", temp.path().display()));
+
+    let vars = TemplateVars::new().var("root", "/srv");
+    config
+        .write_template("data = \"{{root}}/data\"\n", &vars)
+        .unwrap();
+    config.assert("data = \"/srv/data\"\n");
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn write_template_root_outside_fixture() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let config = ChildPath::new(temp.child("config.toml").path());
+    let err = config
+        .write_template("{{root}}", &TemplateVars::new())
+        .unwrap_err();
+    assert!(err.to_string().contains("root"), "{}", err);
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn write_template_escape() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let page = temp.child("page.html");
+    page.write_template("{{{{name}} is {{name}}", &TemplateVars::new())
+        .unwrap();
+    page.assert("{{name}} is page.html");
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn write_template_empty_placeholder() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let err = temp
+        .child("lib.rs")
+        .write_template("format!(\"{{}}\")", &TemplateVars::new())
+        .unwrap_err();
+    assert!(err.to_string().contains("empty"), "{}", err);
+
+    temp.close().unwrap();
+}
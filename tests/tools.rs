@@ -0,0 +1,108 @@
+use assert_fs::fixture::FixtureKind;
+use assert_fs::prelude::*;
+
+#[test]
+fn write_lines() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let file = temp.child("sub/lines.txt");
+
+    file.write_lines(["a", "", "b"]).unwrap();
+    file.assert("a\n\nb\n");
+
+    file.write_lines(Vec::<String>::new()).unwrap();
+    file.assert("");
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn write_lines_under_file() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("file").touch().unwrap();
+
+    let err = temp.child("file/lines.txt").write_lines(["a"]).unwrap_err();
+    assert_eq!(err.kind(), FixtureKind::CreateDir);
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn append() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let file = temp.child("sub/log.txt");
+
+    file.append_str("").unwrap();
+    file.assert("");
+
+    file.append_str("one\n").unwrap();
+    file.append_binary(b"two\n").unwrap();
+    file.assert("one\ntwo\n");
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn read() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let file = temp.child("a.txt");
+
+    file.touch().unwrap();
+    assert_eq!(file.read_to_string().unwrap(), "");
+    assert_eq!(file.read_bytes().unwrap(), b"");
+
+    file.write_binary(b"\xff").unwrap();
+    assert_eq!(file.read_bytes().unwrap(), b"\xff");
+    let err = file.read_to_string().unwrap_err();
+    assert_eq!(err.kind(), FixtureKind::ReadFile);
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn read_missing() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let file = temp.child("missing.txt");
+
+    let err = file.read_to_string().unwrap_err();
+    assert_eq!(err.kind(), FixtureKind::ReadFile);
+    assert!(err.is_not_found());
+    assert_eq!(err.source_path(), Some(file.path()));
+
+    let err = file.read_bytes().unwrap_err();
+    assert!(err.is_not_found());
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn list() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    assert!(temp.list().unwrap().is_empty());
+
+    temp.child("b.txt").touch().unwrap();
+    temp.child("a/z.txt").touch().unwrap();
+    temp.child("a/b/c.txt").touch().unwrap();
+
+    let expected: Vec<std::path::PathBuf> = ["a", "a/b", "a/b/c.txt", "a/z.txt", "b.txt"]
+        .iter()
+        .map(Into::into)
+        .collect();
+    assert_eq!(temp.list().unwrap(), expected);
+
+    let expected: Vec<std::path::PathBuf> =
+        ["b", "b/c.txt", "z.txt"].iter().map(Into::into).collect();
+    assert_eq!(temp.child("a").list().unwrap(), expected);
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn list_missing() {
+    let temp = assert_fs::TempDir::new().unwrap();
+
+    let err = temp.child("missing").list().unwrap_err();
+    assert_eq!(err.kind(), FixtureKind::Walk);
+    assert!(err.is_not_found());
+
+    temp.close().unwrap();
+}
@@ -0,0 +1,96 @@
+use assert_fs::fixture::Txtar;
+use assert_fs::prelude::*;
+use predicates::prelude::*;
+
+const TREE: &str = "\
+A comment.
+-- hello.txt --
+Hello World
+-- sub/empty.txt --
+";
+
+#[test]
+fn parse_comment_and_files() {
+    let tree = Txtar::parse(TREE);
+    assert_eq!(tree.comment(), "A comment.\n");
+    let files: Vec<_> = tree
+        .files()
+        .map(|(p, d)| (p.to_str().unwrap(), d))
+        .collect();
+    assert_eq!(
+        files,
+        [("hello.txt", "Hello World\n"), ("sub/empty.txt", "")]
+    );
+}
+
+#[test]
+fn write_txtar_round_trips() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.write_txtar(TREE).unwrap();
+
+    temp.child("hello.txt").assert("Hello World\n");
+    temp.child("sub/empty.txt").assert("");
+    temp.assert(assert_fs::assert::matches_txtar(TREE).exact());
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn write_txtar_rejects_escaping_paths() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let err = temp
+        .write_txtar("-- good.txt --\nfine\n-- ../evil.txt --\n")
+        .unwrap_err();
+    assert!(err.to_string().contains("escapes"), "{}", err);
+    temp.child("good.txt").assert(predicates::path::missing());
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn matches_txtar_detects_differences() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.write_txtar(TREE).unwrap();
+    temp.child("extra.txt").touch().unwrap();
+
+    assert!(assert_fs::assert::matches_txtar(TREE).eval(temp.path()));
+    assert!(!assert_fs::assert::matches_txtar(TREE)
+        .exact()
+        .eval(temp.path()));
+    assert!(!assert_fs::assert::matches_txtar("-- hello.txt --\nBye\n").eval(temp.path()));
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn matches_txtar_rejects_escaping_paths() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("outside.txt").write_str("x").unwrap();
+    let dir = temp.child("dir");
+    dir.create_dir_all().unwrap();
+
+    let pred = assert_fs::assert::matches_txtar("-- ../outside.txt --\nx");
+    assert!(!pred.eval(dir.path()));
+    let case = pred.find_case(false, dir.path()).unwrap();
+    let error = case.products().next().unwrap().to_string();
+    assert!(error.contains("escapes"), "{}", error);
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn matches_txtar_reports_unreadable_files() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("hello.txt").create_dir_all().unwrap();
+
+    let pred = assert_fs::assert::matches_txtar("-- hello.txt --\nHello\n");
+    let case = pred.find_case(false, temp.path()).unwrap();
+    let violations = case.products().next().unwrap().to_string();
+    assert!(
+        violations.contains("unreadable hello.txt"),
+        "{}",
+        violations
+    );
+
+    temp.close().unwrap();
+}